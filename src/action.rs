@@ -1,4 +1,17 @@
-use std::future::Future;
+use std::{
+    any::Any,
+    future::Future,
+    panic::{AssertUnwindSafe, UnwindSafe, catch_unwind, resume_unwind},
+    pin::Pin,
+    sync::{Arc, Mutex, PoisonError},
+    task::{Context, Poll, ready},
+};
+
+use pin_project::pin_project;
+use tokio::{
+    task::{JoinHandle, spawn_blocking},
+    time::{Duration, Timeout, error::Elapsed, timeout},
+};
 
 use crate::error::Error as RetryError;
 
@@ -23,3 +36,351 @@ impl<R, E, T: Future<Output = Result<R, RetryError<E>>>, F: FnMut() -> T> Action
         self()
     }
 }
+
+/// An action calling an async function with a clone of an argument on each attempt,
+/// created by [`Retry::spawn_fn`](crate::Retry::spawn_fn) function.
+///
+/// Avoids the `move || { let arg = arg.clone(); async move { .. } }` boilerplate when
+/// the action is a free `async fn` taking an owned argument.
+#[derive(Debug, Clone)]
+pub struct AsyncFnAction<F, T> {
+    f: F,
+    arg: T,
+}
+
+impl<F, T, Fut, R, E> AsyncFnAction<F, T>
+where
+    F: FnMut(T) -> Fut,
+    T: Clone,
+    Fut: Future<Output = Result<R, RetryError<E>>>,
+{
+    /// Wraps `f`, called with a clone of `arg` on each attempt.
+    pub const fn new(f: F, arg: T) -> Self {
+        Self { f, arg }
+    }
+}
+
+impl<F, T, Fut, R, E> Action for AsyncFnAction<F, T>
+where
+    F: FnMut(T) -> Fut,
+    T: Clone,
+    Fut: Future<Output = Result<R, RetryError<E>>>,
+{
+    type Item = R;
+    type Error = E;
+    type Future = Fut;
+
+    fn run(&mut self) -> Self::Future {
+        (self.f)(self.arg.clone())
+    }
+}
+
+/// An action handing the same mutable context to each attempt,
+/// created by [`Retry::spawn_stateful`](crate::Retry::spawn_stateful) function.
+///
+/// The returned future can't borrow the context, clone or move out what the
+/// attempt needs.
+#[derive(Debug, Clone)]
+pub struct StatefulAction<Ctx, F> {
+    ctx: Ctx,
+    f: F,
+}
+
+impl<Ctx, F, Fut, R, E> StatefulAction<Ctx, F>
+where
+    F: FnMut(&mut Ctx) -> Fut,
+    Fut: Future<Output = Result<R, RetryError<E>>>,
+{
+    /// Wraps `f`, called with a mutable reference to `ctx` on each attempt.
+    pub const fn new(ctx: Ctx, f: F) -> Self {
+        Self { ctx, f }
+    }
+}
+
+impl<Ctx, F, Fut, R, E> Action for StatefulAction<Ctx, F>
+where
+    F: FnMut(&mut Ctx) -> Fut,
+    Fut: Future<Output = Result<R, RetryError<E>>>,
+{
+    type Item = R;
+    type Error = E;
+    type Future = Fut;
+
+    fn run(&mut self) -> Self::Future {
+        (self.f)(&mut self.ctx)
+    }
+}
+
+/// An action running a synchronous closure on the blocking thread pool,
+/// created by [`Retry::spawn_blocking`](crate::Retry::spawn_blocking) function.
+///
+/// The closure is shared with the blocking task of each attempt, so it must be `Send`
+/// and `'static`. A panic in the closure is resumed when the attempt is awaited.
+///
+/// A blocking task is only cancelled if its runtime shuts down before the task starts,
+/// so the closure never ran: the attempt then calls it inline, on the thread polling
+/// the retry.
+#[derive(Debug)]
+pub struct BlockingAction<F> {
+    f: Arc<Mutex<F>>,
+}
+
+impl<F, R, E> BlockingAction<F>
+where
+    F: FnMut() -> Result<R, RetryError<E>> + Send + 'static,
+    R: Send + 'static,
+    E: Send + 'static,
+{
+    /// Wraps `f`, called on [`tokio::task::spawn_blocking`] on each attempt.
+    pub fn new(f: F) -> Self {
+        Self {
+            f: Arc::new(Mutex::new(f)),
+        }
+    }
+}
+
+impl<F, R, E> Action for BlockingAction<F>
+where
+    F: FnMut() -> Result<R, RetryError<E>> + Send + 'static,
+    R: Send + 'static,
+    E: Send + 'static,
+{
+    type Item = R;
+    type Error = E;
+    type Future = BlockingFuture<F, R, E>;
+
+    fn run(&mut self) -> Self::Future {
+        let f = Arc::clone(&self.f);
+        BlockingFuture {
+            handle: spawn_blocking(move || call(&f)),
+            f: Arc::clone(&self.f),
+        }
+    }
+}
+
+fn call<F: FnMut() -> T, T>(f: &Mutex<F>) -> T {
+    (f.lock().unwrap_or_else(PoisonError::into_inner))()
+}
+
+/// Future produced by the [`BlockingAction`] action.
+#[derive(Debug)]
+pub struct BlockingFuture<F, R, E> {
+    handle: JoinHandle<Result<R, RetryError<E>>>,
+    f: Arc<Mutex<F>>,
+}
+
+impl<F, R, E> Future for BlockingFuture<F, R, E>
+where
+    F: FnMut() -> Result<R, RetryError<E>>,
+{
+    type Output = Result<R, RetryError<E>>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        match ready!(Pin::new(&mut self.handle).poll(cx)) {
+            Ok(result) => Poll::Ready(result),
+            Err(err) if err.is_panic() => resume_unwind(err.into_panic()),
+            // the handle is never aborted, so the runtime shut down before the task
+            // started, and the closure never ran
+            Err(_) => Poll::Ready(call(&self.f)),
+        }
+    }
+}
+
+/// An action wrapper bounding each attempt to a duration,
+/// created by [`Retry::spawn_with_attempt_timeout`](crate::Retry::spawn_with_attempt_timeout) function.
+///
+/// An attempt that doesn't resolve in time is cancelled and turned into a transient error
+/// by `on_timeout`.
+#[derive(Debug, Clone)]
+pub struct AttemptTimeout<A, F> {
+    action: A,
+    per_attempt: Duration,
+    on_timeout: F,
+}
+
+impl<A, F> AttemptTimeout<A, F>
+where
+    A: Action,
+    F: FnMut(Elapsed) -> A::Error + Clone,
+{
+    /// Wraps `action`, so each attempt is bounded by `per_attempt`.
+    pub const fn new(action: A, per_attempt: Duration, on_timeout: F) -> Self {
+        Self {
+            action,
+            per_attempt,
+            on_timeout,
+        }
+    }
+}
+
+impl<A, F> Action for AttemptTimeout<A, F>
+where
+    A: Action,
+    F: FnMut(Elapsed) -> A::Error + Clone,
+{
+    type Item = A::Item;
+    type Error = A::Error;
+    type Future = AttemptTimeoutFuture<A::Future, F>;
+
+    fn run(&mut self) -> Self::Future {
+        AttemptTimeoutFuture {
+            inner: timeout(self.per_attempt, self.action.run()),
+            on_timeout: self.on_timeout.clone(),
+        }
+    }
+}
+
+/// Future produced by the [`AttemptTimeout`] action.
+#[pin_project]
+pub struct AttemptTimeoutFuture<T, F> {
+    #[pin]
+    inner: Timeout<T>,
+    on_timeout: F,
+}
+
+impl<R, E, T, F> Future for AttemptTimeoutFuture<T, F>
+where
+    T: Future<Output = Result<R, RetryError<E>>>,
+    F: FnMut(Elapsed) -> E,
+{
+    type Output = Result<R, RetryError<E>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        match ready!(this.inner.poll(cx)) {
+            Ok(result) => Poll::Ready(result),
+            Err(elapsed) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!("attempt timed out");
+                Poll::Ready(Err(RetryError::transient((this.on_timeout)(elapsed))))
+            }
+        }
+    }
+}
+
+/// An action wrapper catching panics of each attempt,
+/// created by [`Retry::spawn_catch_unwind`](crate::Retry::spawn_catch_unwind) function.
+///
+/// A panic, either while starting or polling an attempt, is turned into a
+/// [`RetryError`] by `on_panic`, which decides whether to retry or abort.
+#[derive(Debug, Clone)]
+pub struct CatchUnwind<A, F> {
+    action: A,
+    on_panic: F,
+}
+
+impl<A, F> CatchUnwind<A, F>
+where
+    A: Action + UnwindSafe,
+    A::Future: UnwindSafe,
+    F: Fn(Box<dyn Any + Send>) -> RetryError<A::Error> + Clone,
+{
+    /// Wraps `action`, so its panics are converted by `on_panic`.
+    pub const fn new(action: A, on_panic: F) -> Self {
+        Self { action, on_panic }
+    }
+}
+
+impl<A, F> Action for CatchUnwind<A, F>
+where
+    A: Action + UnwindSafe,
+    A::Future: UnwindSafe,
+    F: Fn(Box<dyn Any + Send>) -> RetryError<A::Error> + Clone,
+{
+    type Item = A::Item;
+    type Error = A::Error;
+    type Future = CatchUnwindFuture<A::Future, F>;
+
+    fn run(&mut self) -> Self::Future {
+        let (inner, payload) = match catch_unwind(AssertUnwindSafe(|| self.action.run())) {
+            Ok(future) => (Some(future), None),
+            Err(payload) => (None, Some(payload)),
+        };
+        CatchUnwindFuture {
+            inner,
+            payload,
+            on_panic: self.on_panic.clone(),
+        }
+    }
+}
+
+/// Future produced by the [`CatchUnwind`] action.
+#[pin_project]
+pub struct CatchUnwindFuture<T, F> {
+    #[pin]
+    inner: Option<T>,
+    payload: Option<Box<dyn Any + Send>>,
+    on_panic: F,
+}
+
+impl<R, E, T, F> Future for CatchUnwindFuture<T, F>
+where
+    T: Future<Output = Result<R, RetryError<E>>> + UnwindSafe,
+    F: Fn(Box<dyn Any + Send>) -> RetryError<E>,
+{
+    type Output = Result<R, RetryError<E>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut this = self.project();
+        if let Some(inner) = this.inner.as_mut().as_pin_mut() {
+            match catch_unwind(AssertUnwindSafe(|| inner.poll(cx))) {
+                Ok(poll) => return poll,
+                Err(payload) => {
+                    this.inner.set(None);
+                    *this.payload = Some(payload);
+                }
+            }
+        }
+        match this.payload.take() {
+            Some(payload) => {
+                #[cfg(feature = "tracing")]
+                tracing::warn!("attempt panicked");
+                Poll::Ready(Err((this.on_panic)(payload)))
+            }
+            None => Poll::Pending,
+        }
+    }
+}
+
+/// An action wrapper running each attempt in a span tagged with an operation id,
+/// created by [`Retry::spawn_with_operation_id`](crate::Retry::spawn_with_operation_id) function.
+#[cfg(feature = "tracing")]
+#[derive(Debug, Clone)]
+pub struct TracedAction<A> {
+    action: A,
+    operation_id: String,
+    attempt: usize,
+}
+
+#[cfg(feature = "tracing")]
+impl<A: Action> TracedAction<A> {
+    /// Wraps `action`, tagging the span of each attempt with `operation_id`.
+    pub fn new(action: A, operation_id: impl std::fmt::Display) -> Self {
+        Self {
+            action,
+            operation_id: operation_id.to_string(),
+            attempt: 0,
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl<A: Action> Action for TracedAction<A> {
+    type Item = A::Item;
+    type Error = A::Error;
+    type Future = tracing::instrument::Instrumented<A::Future>;
+
+    fn run(&mut self) -> Self::Future {
+        use tracing::Instrument;
+
+        self.attempt = self.attempt.saturating_add(1);
+        let span = tracing::info_span!(
+            "retry_attempt",
+            operation_id = %self.operation_id,
+            attempt = self.attempt
+        );
+        tracing::debug!(parent: &span, "starting retry attempt");
+        let _guard = span.enter();
+        self.action.run().instrument(span.clone())
+    }
+}
@@ -0,0 +1,102 @@
+use std::{
+    future::Future,
+    iter::Empty,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project::pin_project;
+use tokio::time::Duration;
+
+use crate::{
+    action::Action,
+    future::{Park, Retry},
+};
+
+/// The strategy of the underlying retry loop, yielding the delay last awaited from the
+/// async strategy.
+struct NextDelay(Option<Duration>);
+
+impl Iterator for NextDelay {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        self.0.take()
+    }
+}
+
+// `Retry` is only named to expose the constructor next to the other `spawn` functions,
+// the iterator strategy is unused.
+impl<A: Action> Retry<Empty<Duration>, A> {
+    /// Same as [`RetryAsyncStrategy::spawn`].
+    pub fn spawn_with_async_strategy<S, F>(strategy: S, action: A) -> RetryAsyncStrategy<S, F, A>
+    where
+        S: FnMut() -> F,
+        F: Future<Output = Option<Duration>>,
+    {
+        RetryAsyncStrategy::spawn(strategy, action)
+    }
+}
+
+/// Future that drives multiple attempts at an action via an async retry strategy,
+/// created by [`Retry::spawn_with_async_strategy`] or [`RetryAsyncStrategy::spawn`]
+/// function.
+#[pin_project]
+pub struct RetryAsyncStrategy<S, F, A>
+where
+    A: Action,
+{
+    #[pin]
+    retry: Retry<NextDelay, A>,
+    strategy: S,
+    #[pin]
+    delay: Option<F>,
+}
+
+impl<S, F, A> RetryAsyncStrategy<S, F, A>
+where
+    S: FnMut() -> F,
+    F: Future<Output = Option<Duration>>,
+    A: Action,
+{
+    /// Same as [`Retry::spawn`], but each delay is awaited from the async `strategy`,
+    /// e.g. a server-driven backoff. The retry ends with the last error once it
+    /// resolves to `None`.
+    ///
+    /// The strategy is awaited on every transient error, also the ones carrying a
+    /// `retry_after` or `retry_at`, which then replace the awaited delay.
+    pub fn spawn(strategy: S, action: A) -> Self {
+        Self {
+            retry: Retry::spawn(NextDelay(None), action).park_at(Park::Delay),
+            strategy,
+            delay: None,
+        }
+    }
+}
+
+impl<S, F, A> Future for RetryAsyncStrategy<S, F, A>
+where
+    S: FnMut() -> F,
+    F: Future<Output = Option<Duration>>,
+    A: Action,
+{
+    type Output = Result<A::Item, A::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            let poll = if let Some(delay) = this.delay.as_mut().as_pin_mut() {
+                let delay = ready!(delay.poll(cx));
+                this.delay.set(None);
+                this.retry.as_mut().strategy_mut().0 = delay;
+                this.retry.as_mut().unpark(cx)
+            } else {
+                this.retry.as_mut().poll(cx)
+            };
+            if poll.is_ready() || this.retry.parked().is_none() {
+                return poll;
+            }
+            this.delay.set(Some((this.strategy)()));
+        }
+    }
+}
@@ -0,0 +1,124 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use pin_project::pin_project;
+use tokio::time::{Duration, Instant, Sleep, sleep_until};
+
+use crate::{
+    action::Action,
+    future::{Retry, deadline_after},
+};
+
+/// The limit that ended a retry loop created by [`Retry::spawn_bounded`] function.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum StopReason {
+    /// The maximum number of attempts was reached.
+    MaxAttempts,
+    /// The maximum elapsed time was reached.
+    MaxElapsed,
+    /// The strategy ran out of delays before either limit.
+    StrategyExhausted,
+    /// The action returned a permanent error.
+    Permanent,
+}
+
+impl<I, A> Retry<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    /// Same as [`Retry::spawn`], but stops at whichever of `max_attempts` attempts or
+    /// `max_elapsed` time is reached first, resolving to the last error along with the
+    /// [`StopReason`].
+    ///
+    /// `max_attempts` counts the first attempt, so a value of `0` or `1` runs the action
+    /// once. The time limit interrupts the sleep between attempts, but an in-flight
+    /// attempt is awaited, unlike [`Retry::spawn_with_overall_timeout`].
+    pub fn spawn_bounded<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        strategy: T,
+        max_attempts: usize,
+        max_elapsed: Duration,
+        action: A,
+    ) -> RetryBounded<I, A> {
+        RetryBounded::new(strategy.into_iter(), max_attempts, max_elapsed, action)
+    }
+}
+
+/// Future that drives multiple attempts at an action via a retry strategy, bounded by a
+/// number of attempts and an elapsed time, created by [`Retry::spawn_bounded`] function.
+#[pin_project]
+pub struct RetryBounded<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    #[pin]
+    retry: Retry<I, A>,
+    max_attempts: usize,
+    #[pin]
+    deadline: Sleep,
+}
+
+impl<I, A> RetryBounded<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    fn new(strategy: I, max_attempts: usize, max_elapsed: Duration, action: A) -> Self {
+        let mut remaining = max_attempts.saturating_sub(1);
+        let retry = Retry::spawn(strategy, action).admit(move || {
+            let Some(left) = remaining.checked_sub(1) else {
+                #[cfg(feature = "tracing")]
+                tracing::warn!("ending retry: max attempts reached");
+                return false;
+            };
+            remaining = left;
+            true
+        });
+        Self {
+            retry,
+            max_attempts: max_attempts.max(1),
+            deadline: sleep_until(deadline_after(max_elapsed)),
+        }
+    }
+}
+
+impl<I, A> Future for RetryBounded<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    type Output = Result<A::Item, (A::Error, StopReason)>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut this = self.project();
+        if let Poll::Ready(result) = this.retry.as_mut().poll(cx) {
+            return Poll::Ready(result.map_err(|err| {
+                let reason = if this.retry.ended_permanently() {
+                    StopReason::Permanent
+                } else if this.retry.attempts() >= *this.max_attempts {
+                    StopReason::MaxAttempts
+                } else if Instant::now() >= this.deadline.deadline() {
+                    StopReason::MaxElapsed
+                } else {
+                    StopReason::StrategyExhausted
+                };
+                (err, reason)
+            }));
+        }
+        // the deadline only interrupts the wait between attempts
+        if this.retry.is_waiting()
+            && this.deadline.poll(cx).is_ready()
+            && let Some(err) = this.retry.take_last_error()
+        {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("ending retry: max elapsed time reached");
+            return Poll::Ready(Err((err, StopReason::MaxElapsed)));
+        }
+        Poll::Pending
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Fixed point scale of the tokens, so fractional deposits can be tracked atomically.
+const SCALE: u32 = 1_000;
+
+/// A token bucket bounding the total number of retries of many retry loops.
+///
+/// Shared in an [`Arc`](std::sync::Arc) by [`Retry::spawn_with_budget`](crate::Retry::spawn_with_budget),
+/// each retry withdraws one token, and a loop stops as soon as the budget is exhausted.
+/// Call [`RetryBudget::deposit`] on each request to refill the bucket by the deposit
+/// ratio, so retries can't exceed that ratio of the request rate:
+///
+/// ```
+/// use tokio_retry2::RetryBudget;
+///
+/// // at most 2 retries in a burst, then one retry every 10 requests
+/// let budget = RetryBudget::new(2).deposit_ratio(0.1);
+/// assert!(budget.try_withdraw());
+/// assert!(budget.try_withdraw());
+/// assert!(!budget.try_withdraw());
+///
+/// (0..10).for_each(|_| budget.deposit());
+/// assert!(budget.try_withdraw());
+/// ```
+#[derive(Debug)]
+pub struct RetryBudget {
+    tokens: AtomicU64,
+    max_tokens: u64,
+    deposit: u64,
+}
+
+impl RetryBudget {
+    /// Constructs a full budget allowing `max_retries` retries before being refilled.
+    /// Each deposit adds a tenth of a retry by default.
+    #[must_use]
+    pub const fn new(max_retries: u64) -> Self {
+        let max_tokens = max_retries.saturating_mul(SCALE as u64);
+        Self {
+            tokens: AtomicU64::new(max_tokens),
+            max_tokens,
+            deposit: SCALE as u64 / 10,
+        }
+    }
+
+    /// Set the fraction of a retry added by each [`RetryBudget::deposit`].
+    ///
+    /// `ratio` is clamped to `[0.0, 1.0]`, and `NaN` disables deposits.
+    #[must_use]
+    pub fn deposit_ratio(mut self, ratio: f64) -> Self {
+        let ratio = if ratio.is_nan() {
+            0.0
+        } else {
+            ratio.clamp(0.0, 1.0)
+        };
+        #[expect(
+            clippy::cast_possible_truncation,
+            clippy::cast_sign_loss,
+            reason = "lossless: the ratio is clamped to `[0.0, 1.0]`"
+        )]
+        let deposit = (ratio * f64::from(SCALE)).round() as u64;
+        self.deposit = deposit;
+        self
+    }
+
+    /// Refills the budget by the deposit ratio, up to its maximum.
+    pub fn deposit(&self) {
+        let _ = self
+            .tokens
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |tokens| {
+                Some(tokens.saturating_add(self.deposit).min(self.max_tokens))
+            });
+    }
+
+    /// Withdraws the token of one retry, returning `false` if the budget is exhausted.
+    pub fn try_withdraw(&self) -> bool {
+        self.tokens
+            .fetch_update(Ordering::AcqRel, Ordering::Acquire, |tokens| {
+                tokens.checked_sub(u64::from(SCALE))
+            })
+            .is_ok()
+    }
+
+    /// The number of whole retries left in the budget.
+    #[must_use]
+    pub fn remaining(&self) -> u64 {
+        self.tokens.load(Ordering::Acquire) / u64::from(SCALE)
+    }
+}
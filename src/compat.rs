@@ -0,0 +1,46 @@
+use tokio::time::Duration;
+
+use crate::{
+    action::Action,
+    condition::Condition,
+    future::{Retry, RetryIf},
+    notify::NoopNotify,
+};
+
+/// Same as [`Retry::spawn`], as a free function for code migrating from the
+/// original `tokio-retry` crate.
+///
+/// ```
+/// # #[tokio::main]
+/// # async fn main() {
+/// use tokio_retry2::{RetryError, retry, strategy::FixedInterval};
+///
+/// let result = retry(FixedInterval::from_millis(10).take(3), || async {
+///     Ok::<u64, RetryError<()>>(42)
+/// })
+/// .await;
+/// assert_eq!(result, Ok(42));
+/// # }
+/// ```
+pub fn retry<T, A>(strategy: T, action: A) -> Retry<T::IntoIter, A>
+where
+    T: IntoIterator<Item = Duration>,
+    A: Action,
+{
+    Retry::spawn(strategy, action)
+}
+
+/// Same as [`RetryIf::spawn`] without notify, as a free function for code migrating
+/// from the original `tokio-retry` crate.
+pub fn retry_if<T, A, C>(
+    strategy: T,
+    action: A,
+    condition: C,
+) -> RetryIf<T::IntoIter, A, C, NoopNotify>
+where
+    T: IntoIterator<Item = Duration>,
+    A: Action,
+    C: Condition<A::Error>,
+{
+    RetryIf::spawn(strategy, action, condition, NoopNotify)
+}
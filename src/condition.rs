@@ -1,6 +1,43 @@
 /// Specifies under which conditions a retry is attempted.
 pub trait Condition<E> {
     fn should_retry(&mut self, error: &E) -> bool;
+
+    /// Three-way version of [`Condition::should_retry`], mapping `true` to
+    /// [`RetryDecision::Retry`] and `false` to [`RetryDecision::Stop`] by default.
+    fn decide(&mut self, error: &E) -> RetryDecision {
+        if self.should_retry(error) {
+            RetryDecision::Retry
+        } else {
+            RetryDecision::Stop
+        }
+    }
+}
+
+/// The decision taken on a transient error, see [`RetryIf::spawn_decide`](crate::RetryIf::spawn_decide).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RetryDecision {
+    /// Retry after the delay from the strategy, or the error's `retry_after`.
+    Retry,
+    /// Retry without waiting, same as a zero `retry_after`.
+    RetryNow,
+    /// Stop retrying, resolving to the error.
+    Stop,
+}
+
+/// A condition taking a [`RetryDecision`] from a closure,
+/// created by [`RetryIf::spawn_decide`](crate::RetryIf::spawn_decide) function.
+#[derive(Debug, Clone)]
+pub struct Decide<F>(pub F);
+
+impl<E, F: FnMut(&E) -> RetryDecision> Condition<E> for Decide<F> {
+    fn should_retry(&mut self, error: &E) -> bool {
+        self.decide(error) != RetryDecision::Stop
+    }
+
+    fn decide(&mut self, error: &E) -> RetryDecision {
+        (self.0)(error)
+    }
 }
 
 impl<E, F: FnMut(&E) -> bool> Condition<E> for F {
@@ -8,3 +45,56 @@ impl<E, F: FnMut(&E) -> bool> Condition<E> for F {
         self(error)
     }
 }
+
+/// A condition implementation that always retries
+pub struct AlwaysRetry;
+
+impl<E> Condition<E> for AlwaysRetry {
+    fn should_retry(&mut self, _error: &E) -> bool {
+        true
+    }
+}
+
+/// A condition that stops retrying once the same error is repeated `max_repeats` times
+/// in a row, used by [`Retry::spawn_dedup`](crate::Retry::spawn_dedup).
+///
+/// The first occurrence of an error is not a repeat, so a stable error is attempted
+/// `max_repeats + 1` times before giving up, and `MaxRepeats::new(0)` never retries.
+/// A different error resets the count.
+#[derive(Debug, Clone)]
+pub struct MaxRepeats<E> {
+    limit: usize,
+    repeats: usize,
+    last_error: Option<E>,
+}
+
+impl<E> MaxRepeats<E> {
+    /// Constructs a new condition allowing at most `max_repeats` repeats of an error,
+    /// `0` stopping on the first error.
+    #[must_use]
+    pub const fn new(max_repeats: usize) -> Self {
+        Self {
+            limit: max_repeats,
+            repeats: 0,
+            last_error: None,
+        }
+    }
+}
+
+impl<E: PartialEq + Clone> Condition<E> for MaxRepeats<E> {
+    fn should_retry(&mut self, error: &E) -> bool {
+        if self.last_error.as_ref() == Some(error) {
+            self.repeats += 1;
+        } else {
+            self.repeats = 0;
+            self.last_error = Some(error.clone());
+        }
+
+        if self.repeats >= self.limit {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("ending retry: same error repeated `max_repeats` times");
+            return false;
+        }
+        true
+    }
+}
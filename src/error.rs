@@ -1,5 +1,7 @@
 use std::{error, fmt, time::Duration};
 
+use tokio::time::Instant;
+
 const TRANSIENT_ERROR: &str = "transient error";
 const PERMANENT_ERROR: &str = "permanent error";
 
@@ -7,6 +9,26 @@ const PERMANENT_ERROR: &str = "permanent error";
 ///
 /// Based on the two possible values, the operation
 /// may be retried.
+///
+/// The enum is `#[non_exhaustive]`, so new variants can be added without breaking
+/// changes: add a wildcard arm to matches, or inspect errors with the accessors, e.g.
+/// [`Error::is_transient`], [`Error::get_retry_after`] or [`Error::inner`]. The variants
+/// themselves can still be built and destructured directly:
+///
+/// ```
+/// use std::time::Duration;
+/// use tokio_retry2::RetryError;
+///
+/// let err = RetryError::Transient {
+///     err: "busy",
+///     retry_after: Some(Duration::from_secs(1)),
+/// };
+/// if let RetryError::Transient { err, retry_after } = err {
+///     assert_eq!((err, retry_after), ("busy", Some(Duration::from_secs(1))));
+/// }
+/// ```
+#[derive(Clone)]
+#[non_exhaustive]
 pub enum Error<E> {
     /// `Permanent` means that it's impossible to execute the operation
     /// successfully. This error is an early return from the retry operation.
@@ -19,6 +41,11 @@ pub enum Error<E> {
         err: E,
         retry_after: Option<Duration>,
     },
+
+    /// Same as `Transient` with a `retry_after`, but retried at an absolute `Instant`, like
+    /// a HTTP `Retry-After` date. The remaining duration is computed right before sleeping,
+    /// and then handled as a `retry_after`.
+    TransientAt { err: E, retry_at: Instant },
 }
 
 impl<E> Error<E> {
@@ -27,6 +54,13 @@ impl<E> Error<E> {
         Self::Permanent(err)
     }
 
+    /// Creates an permanent error, with the reason why it is not retryable, readable
+    /// with [`Error::reason`]. The payload is wrapped in a [`WithReason`], which the retry
+    /// loop resolves to.
+    pub fn permanent_with(err: E, reason: impl Into<String>) -> Error<WithReason<E>> {
+        Error::Permanent(WithReason::new(err, reason))
+    }
+
     /// Creates a `Result::Err` container with an permanent error.
     #[expect(clippy::missing_errors_doc)]
     pub const fn to_permanent<T>(err: E) -> Result<T, Self> {
@@ -72,15 +106,146 @@ impl<E> Error<E> {
         }
     }
 
+    /// Creates a transient error which is retried at the specified instant. If the instant
+    /// has already passed when the retry loop handles it, the retry happens immediately.
+    pub const fn retry_at(err: E, when: Instant) -> Self {
+        Self::TransientAt {
+            err,
+            retry_at: when,
+        }
+    }
+
     /// Check if error is transient
     pub const fn is_transient(&self) -> bool {
-        matches!(self, Self::Transient { .. })
+        matches!(self, Self::Transient { .. } | Self::TransientAt { .. })
     }
 
     /// Check if error is permanent
     pub const fn is_permanent(&self) -> bool {
         matches!(self, Self::Permanent(_))
     }
+
+    /// The duration to wait before retrying, as given to [`Error::retry_after`], if any.
+    pub const fn get_retry_after(&self) -> Option<Duration> {
+        match self {
+            Self::Transient { retry_after, .. } => *retry_after,
+            _ => None,
+        }
+    }
+
+    /// The instant to retry at, as given to [`Error::retry_at`], if any.
+    pub const fn get_retry_at(&self) -> Option<Instant> {
+        match self {
+            Self::TransientAt { retry_at, .. } => Some(*retry_at),
+            _ => None,
+        }
+    }
+
+    /// Returns a reference to the wrapped error, regardless of its classification.
+    pub const fn inner(&self) -> &E {
+        match self {
+            Self::Permanent(err) | Self::Transient { err, .. } | Self::TransientAt { err, .. } => {
+                err
+            }
+        }
+    }
+
+    /// Consumes the error, discarding its classification.
+    pub fn into_inner(self) -> E {
+        match self {
+            Self::Permanent(err) | Self::Transient { err, .. } | Self::TransientAt { err, .. } => {
+                err
+            }
+        }
+    }
+}
+
+impl<E> Error<WithReason<E>> {
+    /// The reason attached to a permanent error by [`Error::permanent_with`], `None` for
+    /// transient errors.
+    pub fn reason(&self) -> Option<&str> {
+        match self {
+            Self::Permanent(err) => Some(err.reason()),
+            _ => None,
+        }
+    }
+}
+
+/// An error along with the reason why it was deemed non-retryable,
+/// created by [`Error::permanent_with`] function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithReason<E> {
+    err: E,
+    reason: String,
+}
+
+impl<E> WithReason<E> {
+    /// Attaches `reason` to `err`.
+    pub fn new(err: E, reason: impl Into<String>) -> Self {
+        Self {
+            err,
+            reason: reason.into(),
+        }
+    }
+
+    /// The reason why the error is not retryable.
+    pub fn reason(&self) -> &str {
+        &self.reason
+    }
+
+    /// Returns a reference to the wrapped error.
+    pub const fn inner(&self) -> &E {
+        &self.err
+    }
+
+    /// Consumes the wrapper, discarding the reason.
+    pub fn into_inner(self) -> E {
+        self.err
+    }
+}
+
+impl<E: fmt::Display> fmt::Display for WithReason<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}) {}", self.reason, self.err)
+    }
+}
+
+impl<E: error::Error + 'static> error::Error for WithReason<E> {
+    fn source(&self) -> Option<&(dyn error::Error + 'static)> {
+        Some(&self.err)
+    }
+}
+
+#[cfg(feature = "io")]
+impl Error<std::io::Error> {
+    /// Classifies an I/O error: `WouldBlock`, `TimedOut`, `Interrupted` and
+    /// `ConnectionReset` kinds are transient, all others are permanent.
+    #[must_use]
+    pub fn from_io(err: std::io::Error) -> Self {
+        use std::io::ErrorKind;
+
+        Self::from_io_with(err, |kind| {
+            matches!(
+                kind,
+                ErrorKind::WouldBlock
+                    | ErrorKind::TimedOut
+                    | ErrorKind::Interrupted
+                    | ErrorKind::ConnectionReset
+            )
+        })
+    }
+
+    /// Same as [`Error::from_io`], with the transient kinds decided by `is_transient`.
+    pub fn from_io_with(
+        err: std::io::Error,
+        is_transient: impl FnOnce(std::io::ErrorKind) -> bool,
+    ) -> Self {
+        if is_transient(err.kind()) {
+            Self::transient(err)
+        } else {
+            Self::permanent(err)
+        }
+    }
 }
 
 impl<E: fmt::Display> fmt::Display for Error<E> {
@@ -93,6 +258,9 @@ impl<E: fmt::Display> fmt::Display for Error<E> {
                     write!(f, "Transient error: {err}")
                 }
             }
+            Self::TransientAt { err, retry_at } => {
+                write!(f, "Transient error (retry at {retry_at:?}): {err}")
+            }
             Self::Permanent(error) => write!(f, "Permanent error: {error}"),
         }
     }
@@ -108,7 +276,8 @@ where
             Self::Transient {
                 ref err,
                 retry_after: _,
-            } => ("Transient", err as &dyn fmt::Debug),
+            }
+            | Self::TransientAt { ref err, .. } => ("Transient", err as &dyn fmt::Debug),
         };
         f.debug_tuple(name).field(err).finish()
     }
@@ -121,7 +290,7 @@ where
     fn description(&self) -> &str {
         match *self {
             Self::Permanent(_) => PERMANENT_ERROR,
-            Self::Transient { .. } => TRANSIENT_ERROR,
+            Self::Transient { .. } | Self::TransientAt { .. } => TRANSIENT_ERROR,
         }
     }
 
@@ -131,7 +300,8 @@ where
             | Self::Transient {
                 ref err,
                 retry_after: _,
-            } => err.source(),
+            }
+            | Self::TransientAt { ref err, .. } => err.source(),
         }
     }
 
@@ -169,13 +339,25 @@ where
                     retry_after: other_retry_after,
                 },
             ) => self_err == other_err && self_retry_after == other_retry_after,
+            (
+                Self::TransientAt {
+                    err: self_err,
+                    retry_at: self_retry_at,
+                },
+                Self::TransientAt {
+                    err: other_err,
+                    retry_at: other_retry_at,
+                },
+            ) => self_err == other_err && self_retry_at == other_retry_at,
             _ => false,
         }
     }
 }
 
+impl<E> Eq for Error<E> where E: Eq {}
+
 #[cfg(feature = "implicit_results")]
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum RetryResult<T, E> {
     Ok(T),
     Err(Error<E>),
@@ -270,6 +452,36 @@ mod test {
         assert_eq!(e, Error::Permanent("err"));
     }
 
+    #[test]
+    fn create_permanent_error_with_reason() {
+        let e = Error::permanent_with("err", "invalid credentials");
+        assert!(e.is_permanent());
+        assert_eq!(e.reason(), Some("invalid credentials"));
+        assert_eq!(e.to_string(), "Permanent error: (invalid credentials) err");
+        assert_eq!(
+            e.into_inner(),
+            WithReason::new("err", "invalid credentials")
+        );
+
+        let e = Error::transient(WithReason::new("err", "invalid credentials"));
+        assert_eq!(e.reason(), None);
+    }
+
+    #[test]
+    fn inner_returns_payload_of_every_variant() {
+        let errors = [
+            Error::permanent("err"),
+            Error::transient("err"),
+            Error::retry_after("err", Duration::from_secs(1)),
+            Error::retry_at("err", Instant::now()),
+        ];
+
+        for e in errors {
+            assert_eq!(e.inner(), &"err");
+            assert_eq!(e.into_inner(), "err");
+        }
+    }
+
     #[test]
     fn create_transient_error() {
         let e = Error::transient("err");
@@ -295,6 +507,71 @@ mod test {
         );
     }
 
+    #[test]
+    fn compares_classification_and_payload() {
+        assert_eq!(Error::transient(42), Error::transient(42));
+        assert_ne!(Error::transient(42), Error::permanent(42));
+        assert_ne!(
+            Error::transient(42),
+            Error::retry_after(42, Duration::from_secs(1))
+        );
+        assert_ne!(
+            Error::permanent_with(42, "reason"),
+            Error::permanent_with(42, "other reason")
+        );
+    }
+
+    #[test]
+    fn clones_error() {
+        let e = Error::permanent_with(String::from("err"), "reason");
+        assert_eq!(e.clone(), e);
+
+        let e = Error::retry_after(42, Duration::from_secs(1));
+        assert_eq!(e.clone(), e);
+    }
+
+    #[test]
+    fn create_transient_error_with_retry_at() {
+        let when = Instant::now();
+        let e = Error::retry_at("err", when);
+        assert!(e.is_transient());
+        assert_eq!(
+            e,
+            Error::TransientAt {
+                err: "err",
+                retry_at: when,
+            }
+        );
+        assert_ne!(e, Error::transient("err"));
+    }
+
+    #[test]
+    #[cfg(feature = "io")]
+    fn classifies_io_errors() {
+        use std::io::{self, ErrorKind};
+
+        for kind in [
+            ErrorKind::WouldBlock,
+            ErrorKind::TimedOut,
+            ErrorKind::Interrupted,
+            ErrorKind::ConnectionReset,
+        ] {
+            assert!(Error::from_io(io::Error::from(kind)).is_transient());
+        }
+        for kind in [
+            ErrorKind::NotFound,
+            ErrorKind::PermissionDenied,
+            ErrorKind::InvalidData,
+        ] {
+            assert!(Error::from_io(io::Error::from(kind)).is_permanent());
+        }
+
+        let e = Error::from_io_with(io::Error::from(ErrorKind::NotFound), |kind| {
+            kind == ErrorKind::NotFound
+        });
+        assert!(e.is_transient());
+    }
+
     #[test]
     fn map_transient_keeps_ok() {
         let result: Result<i32, Error<()>> = Ok(42).map_transient_err();
@@ -359,6 +636,16 @@ mod test {
         assert_eq!(debug, "Permanent(\"permanent error\")");
     }
 
+    #[test]
+    fn debug_permanent_error_with_reason() {
+        let error = Error::permanent_with(PERMANENT_ERROR, "invalid credentials");
+        let debug = format!("{error:?}");
+        assert_eq!(
+            debug,
+            "Permanent(WithReason { err: \"permanent error\", reason: \"invalid credentials\" })"
+        );
+    }
+
     #[test]
     fn debug_transient_error() {
         let error = Error::Transient {
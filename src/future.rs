@@ -1,18 +1,32 @@
 use std::{
-    cmp, error, fmt,
+    any::Any,
+    cmp,
+    collections::VecDeque,
+    error, fmt,
     future::Future,
     iter::{IntoIterator, Iterator},
+    panic::UnwindSafe,
     pin::Pin,
-    task::{Context, Poll},
+    sync::Arc,
+    task::{Context, Poll, ready},
 };
 
 use pin_project::pin_project;
-use tokio::time::{Duration, Instant, Sleep, sleep_until};
+use tokio::{
+    runtime::Handle,
+    sync::mpsc::Sender,
+    time::{Duration, Instant, Sleep, error::Elapsed, sleep_until},
+};
 
 use super::{action::Action, condition::Condition};
+#[cfg(feature = "tracing")]
+use crate::action::TracedAction;
 use crate::{
+    action::{AsyncFnAction, AttemptTimeout, BlockingAction, CatchUnwind, StatefulAction},
+    budget::RetryBudget,
+    condition::{AlwaysRetry, Decide, MaxRepeats, RetryDecision},
     error::Error as RetryError,
-    notify::{EmptyNotify, Notify},
+    notify::{NoopNotify, Notify},
 };
 
 #[pin_project(project = RetryStateProj)]
@@ -22,6 +36,14 @@ where
 {
     Running(#[pin] A::Future),
     Sleeping(#[pin] Sleep),
+    Yielding,
+    /// Parked at a [`Park`] point, until the wrapper driving the loop unparks it.
+    Parked {
+        /// The delay to wait, when parked instead of sleeping.
+        delay: Duration,
+        /// The `retry_after` of the error, when parked before the delay.
+        retry_after: Option<Duration>,
+    },
 }
 
 impl<A: Action> RetryState<A> {
@@ -29,10 +51,25 @@ impl<A: Action> RetryState<A> {
         match self.project() {
             RetryStateProj::Running(future) => RetryFuturePoll::Running(future.poll(cx)),
             RetryStateProj::Sleeping(future) => RetryFuturePoll::Sleeping(future.poll(cx)),
+            RetryStateProj::Yielding => RetryFuturePoll::Sleeping(Poll::Ready(())),
+            RetryStateProj::Parked { .. } => RetryFuturePoll::Sleeping(Poll::Pending),
         }
     }
 }
 
+/// A point where a retry loop parks instead of going on, handing control over to the
+/// wrapper driving it, e.g. to wait with another timer. The loop stays pending until
+/// the wrapper unparks it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Park {
+    /// Before pulling the next delay from the strategy, keeping the error as the last one.
+    Delay,
+    /// Instead of sleeping a non-zero delay.
+    Sleep,
+    /// After sleeping the delay, before the next attempt.
+    Attempt,
+}
+
 enum RetryFuturePoll<A>
 where
     A: Action,
@@ -41,7 +78,30 @@ where
     Sleeping(Poll<()>),
 }
 
+/// Callback deciding whether a permanent error resumes the retry loop with a new strategy.
+type Resume<I, E> = Box<dyn FnMut(&E) -> Option<I> + Send>;
+
+/// Callback invoked with the failed attempt, its error and the actual delay before the next one.
+type OnRetry<E> = Box<dyn FnMut(usize, &E, Duration) + Send>;
+
+/// Callback admitting a retry once it is scheduled, i.e. the strategy yielded a delay.
+type Admit = Box<dyn FnMut() -> bool + Send>;
+
+/// A boxed retry future, as produced by [`Retry::spawn_boxed`].
+pub type BoxedRetry<T, E> = Pin<Box<dyn Future<Output = Result<T, E>> + Send>>;
+
 /// Future that drives multiple attempts at an action via a retry strategy.
+///
+/// A zero delay doesn't sleep, but yields to the scheduler like
+/// [`tokio::task::yield_now`], so a tight retry loop doesn't starve other tasks.
+///
+/// Once an attempt succeeds, the future resolves right away: the action isn't run
+/// again, and no further delay is slept. Likewise, a failed attempt that won't be retried
+/// resolves without sleeping, so `n` attempts only sleep the `n - 1` delays between them.
+///
+/// The future is neither `Unpin`, since it holds a [`Sleep`], nor `Send`, since its
+/// notify is boxed without a `Send` bound. See [`Retry::spawn_boxed`] to
+/// store it in a struct.
 #[pin_project]
 pub struct Retry<I, A>
 where
@@ -49,7 +109,7 @@ where
     A: Action,
 {
     #[pin]
-    retry_if: RetryIf<I, A, fn(&A::Error) -> bool, Box<dyn Notify<A::Error>>>,
+    retry_if: RetryIf<I, A, AlwaysRetry, Box<dyn Notify<A::Error>>>,
 }
 
 impl<I, A> Retry<I, A>
@@ -59,12 +119,7 @@ where
 {
     pub fn spawn<T: IntoIterator<IntoIter = I, Item = Duration>>(strategy: T, action: A) -> Self {
         Self {
-            retry_if: RetryIf::spawn(
-                strategy,
-                action,
-                (|_| true) as fn(&A::Error) -> bool,
-                Box::new(EmptyNotify),
-            ),
+            retry_if: RetryIf::spawn(strategy, action, AlwaysRetry, Box::new(NoopNotify)),
         }
     }
 
@@ -77,14 +132,430 @@ where
         notify: N,
     ) -> Self {
         Self {
-            retry_if: RetryIf::spawn(
-                strategy,
-                action,
-                (|_| true) as fn(&A::Error) -> bool,
-                Box::new(notify),
-            ),
+            retry_if: RetryIf::spawn(strategy, action, AlwaysRetry, Box::new(notify)),
+        }
+    }
+
+    /// Same as [`Retry::spawn`], but the backoff sleeps are driven by the timer of the
+    /// runtime behind `handle`, instead of the ambient one polling the future.
+    pub fn spawn_on<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        handle: &Handle,
+        strategy: T,
+        action: A,
+    ) -> Self {
+        let mut retry = Self::spawn(strategy, action);
+        retry.retry_if.handle = Some(handle.clone());
+        retry
+    }
+
+    /// Same as [`Retry::spawn`], but calls `once` the first time a failed attempt is
+    /// retried, e.g. to log that an operation required retries. Unlike [`Notify`], it is
+    /// called at most once, and never if the first attempt succeeds or isn't retried.
+    pub fn spawn_on_first_retry<T, F>(strategy: T, action: A, once: F) -> Self
+    where
+        T: IntoIterator<IntoIter = I, Item = Duration>,
+        F: FnOnce() + Send + 'static,
+    {
+        let mut retry = Self::spawn(strategy, action);
+        let mut once = Some(once);
+        retry.retry_if.on_retry = Some(Box::new(move |_, _, _| {
+            if let Some(once) = once.take() {
+                once();
+            }
+        }));
+        retry
+    }
+
+    /// Same as [`Retry::spawn`], but sends a [`RetryProgress`] onto `tx` on each failure
+    /// that is retried, e.g. to feed a progress bar.
+    ///
+    /// Progress is best-effort: messages are dropped when the channel is full or closed,
+    /// so the retry loop never waits on the receiver.
+    pub fn spawn_with_progress<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        strategy: T,
+        action: A,
+        tx: Sender<RetryProgress>,
+    ) -> Self
+    where
+        A::Error: fmt::Debug,
+    {
+        let mut retry = Self::spawn(strategy, action);
+        retry.retry_if.on_retry = Some(Box::new(move |attempt, err, next_delay| {
+            let _ = tx.try_send(RetryProgress {
+                attempt,
+                last_error_debug: format!("{err:?}"),
+                next_delay,
+            });
+        }));
+        retry
+    }
+
+    /// Same as [`Retry::spawn`], but each retry withdraws from the shared `budget`, and
+    /// the loop stops with the last error as soon as it is exhausted. See [`RetryBudget`].
+    ///
+    /// Only scheduled retries withdraw: an error that isn't retried, e.g. because the
+    /// strategy is exhausted, leaves the budget untouched.
+    pub fn spawn_with_budget<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        strategy: T,
+        action: A,
+        budget: Arc<RetryBudget>,
+    ) -> Self {
+        Self::spawn(strategy, action).admit(move || {
+            let withdrawn = budget.try_withdraw();
+            #[cfg(feature = "tracing")]
+            if !withdrawn {
+                tracing::warn!("ending retry: retry budget exhausted");
+            }
+            withdrawn
+        })
+    }
+
+    /// Same as [`Retry::spawn`], but stops retrying once an error is repeated
+    /// `max_repeats` times in a row, i.e. after `max_repeats + 1` identical errors.
+    /// See [`MaxRepeats`].
+    pub fn spawn_dedup<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        strategy: T,
+        action: A,
+        max_repeats: usize,
+    ) -> RetryIf<I, A, MaxRepeats<A::Error>, NoopNotify>
+    where
+        A::Error: PartialEq + Clone,
+    {
+        RetryIf::spawn(strategy, action, MaxRepeats::new(max_repeats), NoopNotify)
+    }
+
+    /// Same as [`Retry::spawn`], but a permanent error is handed to `on_permanent`
+    /// instead of ending the retry. If it returns a new strategy, the retry loop resumes
+    /// with it, otherwise the permanent error is returned.
+    pub fn spawn_resumable<
+        T: IntoIterator<IntoIter = I, Item = Duration>,
+        R: FnMut(&A::Error) -> Option<I> + Send + 'static,
+    >(
+        strategy: T,
+        action: A,
+        on_permanent: R,
+    ) -> Self {
+        let mut retry = Self::spawn(strategy, action);
+        retry.retry_if.resume = Some(Box::new(on_permanent));
+        retry
+    }
+}
+
+impl<I, A> Retry<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    /// Same as [`Retry::spawn`], but the whole retry loop is bounded by `timeout`.
+    ///
+    /// Unlike wrapping the future in [`tokio::time::timeout`], which resolves to a
+    /// generic `Elapsed` and drops the errors seen so far, reaching the timeout resolves
+    /// to the *last observed* transient error. The first attempt is always awaited, since
+    /// there is no error to report before it completes.
+    pub fn spawn_with_overall_timeout<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        strategy: T,
+        action: A,
+        timeout: Duration,
+    ) -> RetryWithTimeout<I, A> {
+        RetryWithTimeout {
+            retry: Self::spawn(strategy, action),
+            deadline: sleep_until(deadline_after(timeout)),
+        }
+    }
+
+    /// Same as [`Retry::spawn`], but races the retry loop against a shutdown `signal`.
+    ///
+    /// If `signal` resolves first, the in-flight attempt or backoff sleep is dropped and
+    /// the future resolves with [`RetryOutcome::ShutdownRequested`].
+    pub fn spawn_until_signal<T: IntoIterator<IntoIter = I, Item = Duration>, S: Future>(
+        strategy: T,
+        action: A,
+        signal: S,
+    ) -> RetryUntilSignal<I, A, S> {
+        RetryUntilSignal {
+            retry: Self::spawn(strategy, action),
+            signal,
+        }
+    }
+
+    /// Same as [`Retry::spawn`], but each attempt is bounded by `per_attempt`.
+    /// An attempt that doesn't resolve in time is cancelled and retried as a transient
+    /// error, converted from [`Elapsed`]. See [`Retry::spawn_with_attempt_timeout_err`]
+    /// to customize the produced error.
+    pub fn spawn_with_attempt_timeout<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        strategy: T,
+        action: A,
+        per_attempt: Duration,
+    ) -> Retry<I, AttemptTimeout<A, fn(Elapsed) -> A::Error>>
+    where
+        A::Error: From<Elapsed>,
+    {
+        Retry::spawn(
+            strategy,
+            AttemptTimeout::new(action, per_attempt, From::from),
+        )
+    }
+
+    /// Same as [`Retry::spawn_with_attempt_timeout`], with the transient error of a timed
+    /// out attempt produced by `on_timeout`.
+    pub fn spawn_with_attempt_timeout_err<
+        T: IntoIterator<IntoIter = I, Item = Duration>,
+        F: FnMut(Elapsed) -> A::Error + Clone,
+    >(
+        strategy: T,
+        action: A,
+        per_attempt: Duration,
+        on_timeout: F,
+    ) -> Retry<I, AttemptTimeout<A, F>> {
+        Retry::spawn(
+            strategy,
+            AttemptTimeout::new(action, per_attempt, on_timeout),
+        )
+    }
+
+    /// Same as [`Retry::spawn`], but a panicking attempt is caught and converted by
+    /// `on_panic` into a [`RetryError`], deciding whether to retry or abort, instead of
+    /// panicking the whole retry future.
+    pub fn spawn_catch_unwind<
+        T: IntoIterator<IntoIter = I, Item = Duration>,
+        F: Fn(Box<dyn Any + Send>) -> RetryError<A::Error> + Clone,
+    >(
+        strategy: T,
+        action: A,
+        on_panic: F,
+    ) -> Retry<I, CatchUnwind<A, F>>
+    where
+        A: UnwindSafe,
+        A::Future: UnwindSafe,
+    {
+        Retry::spawn(strategy, CatchUnwind::new(action, on_panic))
+    }
+
+    /// Same as [`Retry::spawn`], but boxes the future so it is `Unpin` and `Send`, e.g. to
+    /// store it in a struct field and await it later without pinning boilerplate.
+    pub fn spawn_boxed<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        strategy: T,
+        action: A,
+    ) -> BoxedRetry<A::Item, A::Error>
+    where
+        I: Send + 'static,
+        A: Send + 'static,
+        A::Future: Send,
+        A::Item: Send,
+        A::Error: Send,
+    {
+        Box::pin(RetryIf::spawn(strategy, action, AlwaysRetry, NoopNotify))
+    }
+
+    /// Same as [`Retry::spawn`], but each attempt runs in a `retry_attempt` span recording
+    /// `operation_id` and the attempt number, to filter the logs of one operation.
+    #[cfg(feature = "tracing")]
+    pub fn spawn_with_operation_id<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        strategy: T,
+        action: A,
+        operation_id: impl fmt::Display,
+    ) -> Retry<I, TracedAction<A>> {
+        Retry::spawn(strategy, TracedAction::new(action, operation_id))
+    }
+
+    /// Set a hard cap on the number of attempts, `None` retrying forever.
+    /// See [`RetryIf::safety_cap`].
+    #[must_use]
+    pub fn safety_cap(mut self, cap: Option<usize>) -> Self {
+        self.retry_if = self.retry_if.safety_cap(cap);
+        self
+    }
+
+    /// Caps the number of attempts to [`RetryIf::DEFAULT_SAFETY_CAP`].
+    /// See [`RetryIf::with_safety_cap`].
+    #[must_use]
+    pub fn with_safety_cap(mut self) -> Self {
+        self.retry_if = self.retry_if.with_safety_cap();
+        self
+    }
+
+    /// Whether the strategy is advanced when an error carries a `retry_after` duration.
+    /// See [`RetryIf::advance_on_retry_after`].
+    #[must_use]
+    pub fn advance_on_retry_after(mut self, advance: bool) -> Self {
+        self.retry_if = self.retry_if.advance_on_retry_after(advance);
+        self
+    }
+
+    /// Same as [`Retry::spawn`], but resolves along with the total time elapsed from
+    /// the first attempt until resolution, on success and on failure.
+    pub fn spawn_timed<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        strategy: T,
+        action: A,
+    ) -> RetryTimed<I, A> {
+        RetryTimed {
+            retry: Self::spawn(strategy, action),
+            start: None,
+        }
+    }
+
+    /// Same as [`Retry::spawn`], but on failure resolves with a [`RetryFailure`] detailing
+    /// the final error, the number of attempts and the last `retry_after` observed.
+    pub fn spawn_detailed<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        strategy: T,
+        action: A,
+    ) -> RetryDetailed<I, A> {
+        RetryDetailed {
+            retry: Self::spawn(strategy, action),
         }
     }
+
+    /// Same as [`Retry::spawn`], but on failure resolves with every error encountered,
+    /// oldest first, ending with the final error.
+    ///
+    /// Errors are buffered for the whole retry loop, so at most the `max_errors` most
+    /// recent ones are kept. The final error is always kept, so a `max_errors` of `0`
+    /// behaves like `1`.
+    pub fn spawn_collect_errors<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        strategy: T,
+        action: A,
+        max_errors: usize,
+    ) -> RetryCollectErrors<I, A> {
+        let mut retry = Self::spawn(strategy, action);
+        retry.retry_if.error_log = Some(ErrorLog {
+            errors: VecDeque::new(),
+            max_errors: max_errors.max(1),
+        });
+        RetryCollectErrors { retry }
+    }
+
+    /// Only retries once `admit` returns `true`, called when a retry is scheduled.
+    pub(crate) fn admit<F: FnMut() -> bool + Send + 'static>(mut self, admit: F) -> Self {
+        self.retry_if.admit = Some(Box::new(admit));
+        self
+    }
+
+    /// Parks the loop at `park`, see [`Retry::unpark`].
+    pub(crate) const fn park_at(mut self, park: Park) -> Self {
+        self.retry_if.park = Some(park);
+        self
+    }
+
+    /// The delay to wait while parked, zero unless parked at [`Park::Sleep`], or `None`
+    /// if the loop isn't parked.
+    pub(crate) const fn parked(&self) -> Option<Duration> {
+        match self.retry_if.state {
+            RetryState::Parked { delay, .. } => Some(delay),
+            _ => None,
+        }
+    }
+
+    /// Resumes a parked loop, polling it once.
+    pub(crate) fn unpark(
+        self: Pin<&mut Self>,
+        cx: &mut Context,
+    ) -> Poll<Result<A::Item, A::Error>> {
+        self.project().retry_if.unpark(cx)
+    }
+
+    /// Whether the loop is waiting between two attempts.
+    pub(crate) const fn is_waiting(&self) -> bool {
+        matches!(
+            self.retry_if.state,
+            RetryState::Sleeping(_) | RetryState::Yielding
+        )
+    }
+
+    pub(crate) fn strategy_mut(self: Pin<&mut Self>) -> &mut I {
+        self.project().retry_if.project().strategy
+    }
+
+    pub(crate) fn take_last_error(self: Pin<&mut Self>) -> Option<A::Error> {
+        self.project().retry_if.take_last_error()
+    }
+
+    pub(crate) const fn attempts(&self) -> usize {
+        self.retry_if.attempts
+    }
+
+    /// Whether the loop resolved to a permanent error.
+    pub(crate) const fn ended_permanently(&self) -> bool {
+        self.retry_if.permanent
+    }
+
+    const fn last_retry_after(&self) -> Option<Duration> {
+        self.retry_if.last_retry_after
+    }
+}
+
+impl<I, F, Arg, Fut, R, E> Retry<I, AsyncFnAction<F, Arg>>
+where
+    I: Iterator<Item = Duration>,
+    F: FnMut(Arg) -> Fut,
+    Arg: Clone,
+    Fut: Future<Output = Result<R, RetryError<E>>>,
+{
+    /// Same as [`Retry::spawn`], calling the async function `f` with a clone of `arg` on
+    /// each attempt. See [`AsyncFnAction`].
+    ///
+    /// An `async fn` without arguments can be passed to [`Retry::spawn`] directly.
+    ///
+    /// ```
+    /// # use tokio_retry2::{Retry, RetryError};
+    /// # use tokio_retry2::strategy::FixedInterval;
+    /// async fn fetch(url: String) -> Result<usize, RetryError<()>> {
+    ///     Ok(url.len())
+    /// }
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// let url = String::from("https://example.com");
+    /// let result = Retry::spawn_fn(FixedInterval::from_millis(10).take(3), fetch, url).await;
+    /// assert_eq!(result, Ok(19));
+    /// # }
+    /// ```
+    pub fn spawn_fn<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        strategy: T,
+        f: F,
+        arg: Arg,
+    ) -> Self {
+        Self::spawn(strategy, AsyncFnAction::new(f, arg))
+    }
+}
+
+impl<I, Ctx, F, Fut, R, E> Retry<I, StatefulAction<Ctx, F>>
+where
+    I: Iterator<Item = Duration>,
+    F: FnMut(&mut Ctx) -> Fut,
+    Fut: Future<Output = Result<R, RetryError<E>>>,
+{
+    /// Same as [`Retry::spawn`], handing `initial` as a mutable context to each attempt.
+    /// See [`StatefulAction`].
+    ///
+    /// The context is kept across attempts, so mutations made before a transient
+    /// failure are seen by the next attempt, e.g. to reuse a cached connection.
+    pub fn spawn_stateful<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        initial: Ctx,
+        strategy: T,
+        action: F,
+    ) -> Self {
+        Self::spawn(strategy, StatefulAction::new(initial, action))
+    }
+}
+
+impl<I, F, R, E> Retry<I, BlockingAction<F>>
+where
+    I: Iterator<Item = Duration>,
+    F: FnMut() -> Result<R, RetryError<E>> + Send + 'static,
+    R: Send + 'static,
+    E: Send + 'static,
+{
+    /// Same as [`Retry::spawn`], running each attempt of the synchronous `action` on
+    /// [`tokio::task::spawn_blocking`], so CPU-bound attempts don't block the async
+    /// worker threads. See [`BlockingAction`].
+    ///
+    /// An attempt cancelled by the shutdown of its runtime calls `action` inline instead.
+    pub fn spawn_blocking<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        strategy: T,
+        action: F,
+    ) -> Self {
+        Self::spawn(strategy, BlockingAction::new(action))
+    }
 }
 
 impl<I, A> Future for Retry<I, A>
@@ -100,8 +571,231 @@ where
     }
 }
 
+/// Future that drives multiple attempts at an action via a retry strategy, bounded by an overall timeout,
+/// created by [`Retry::spawn_with_overall_timeout`] function.
+#[pin_project]
+pub struct RetryWithTimeout<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    #[pin]
+    retry: Retry<I, A>,
+    #[pin]
+    deadline: Sleep,
+}
+
+impl<I, A> Future for RetryWithTimeout<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    type Output = Result<A::Item, A::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut this = self.project();
+        if let Poll::Ready(result) = this.retry.as_mut().poll(cx) {
+            return Poll::Ready(result);
+        }
+        if this.deadline.poll(cx).is_ready()
+            && let Some(err) = this.retry.take_last_error()
+        {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("ending retry: overall timeout reached");
+            return Poll::Ready(Err(err));
+        }
+        Poll::Pending
+    }
+}
+
+/// Future that drives multiple attempts at an action via a retry strategy, measuring the elapsed time,
+/// created by [`Retry::spawn_timed`] function.
+#[pin_project]
+pub struct RetryTimed<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    #[pin]
+    retry: Retry<I, A>,
+    start: Option<Instant>,
+}
+
+impl<I, A> Future for RetryTimed<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    type Output = Result<(A::Item, Duration), (A::Error, Duration)>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        let start = *this.start.get_or_insert_with(Instant::now);
+        this.retry.poll(cx).map(|result| {
+            let elapsed = start.elapsed();
+            result
+                .map(|item| (item, elapsed))
+                .map_err(|err| (err, elapsed))
+        })
+    }
+}
+
+/// The failure of a retry loop created by [`Retry::spawn_detailed`] function.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryFailure<E> {
+    /// The error of the final attempt.
+    pub error: E,
+    /// The number of attempts, counting the first one.
+    pub attempts: usize,
+    /// The last `retry_after` or `retry_at` carried by an error, the final one included,
+    /// e.g. to log that the server kept asking to back off.
+    pub last_retry_after: Option<Duration>,
+}
+
+/// Future that drives multiple attempts at an action via a retry strategy, detailing the failure,
+/// created by [`Retry::spawn_detailed`] function.
+#[pin_project]
+pub struct RetryDetailed<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    #[pin]
+    retry: Retry<I, A>,
+}
+
+impl<I, A> Future for RetryDetailed<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    type Output = Result<A::Item, RetryFailure<A::Error>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut this = self.project();
+        this.retry.as_mut().poll(cx).map(|result| {
+            result.map_err(|error| RetryFailure {
+                error,
+                attempts: this.retry.attempts(),
+                last_retry_after: this.retry.last_retry_after(),
+            })
+        })
+    }
+}
+
+/// Future that drives multiple attempts at an action via a retry strategy, collecting the errors,
+/// created by [`Retry::spawn_collect_errors`] function.
+#[pin_project]
+pub struct RetryCollectErrors<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    #[pin]
+    retry: Retry<I, A>,
+}
+
+/// The most recent errors of the retried attempts, see [`RetryCollectErrors`].
+struct ErrorLog<E> {
+    errors: VecDeque<E>,
+    max_errors: usize,
+}
+
+impl<E> ErrorLog<E> {
+    fn push(&mut self, err: E) {
+        if self.errors.len() >= self.max_errors {
+            self.errors.pop_front();
+        }
+        self.errors.push_back(err);
+    }
+}
+
+impl<I, A> Future for RetryCollectErrors<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    type Output = Result<A::Item, Vec<A::Error>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut retry = self.project().retry;
+        let result = ready!(retry.as_mut().poll(cx));
+        Poll::Ready(result.map_err(|err| {
+            // each retried error is logged when its retry is scheduled
+            let Some(error_log) = retry.project().retry_if.project().error_log.as_mut() else {
+                return vec![err];
+            };
+            error_log.push(err);
+            std::mem::take(&mut error_log.errors).into()
+        }))
+    }
+}
+
+/// Progress of a retry loop, sent by [`Retry::spawn_with_progress`] on each retried failure.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryProgress {
+    /// The failed attempt, starting at `1`.
+    pub attempt: usize,
+    /// The `Debug` representation of the error of the failed attempt.
+    pub last_error_debug: String,
+    /// The delay before the next attempt.
+    pub next_delay: Duration,
+}
+
+/// The outcome of a retry loop raced against a shutdown signal.
+#[derive(Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum RetryOutcome<T, E> {
+    /// The retry loop resolved before the signal.
+    Completed(Result<T, E>),
+    /// The signal resolved first, after `attempts` attempts were started.
+    ShutdownRequested { attempts: usize },
+}
+
+/// Future that drives multiple attempts at an action via a retry strategy until a shutdown signal,
+/// created by [`Retry::spawn_until_signal`] function.
+#[pin_project]
+pub struct RetryUntilSignal<I, A, S>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+    S: Future,
+{
+    #[pin]
+    retry: Retry<I, A>,
+    #[pin]
+    signal: S,
+}
+
+impl<I, A, S> Future for RetryUntilSignal<I, A, S>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+    S: Future,
+{
+    type Output = RetryOutcome<A::Item, A::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut this = self.project();
+        if let Poll::Ready(result) = this.retry.as_mut().poll(cx) {
+            return Poll::Ready(RetryOutcome::Completed(result));
+        }
+        if this.signal.poll(cx).is_ready() {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("ending retry: shutdown requested");
+            return Poll::Ready(RetryOutcome::ShutdownRequested {
+                attempts: this.retry.attempts(),
+            });
+        }
+        Poll::Pending
+    }
+}
+
 /// Future that drives multiple attempts at an action via a retry strategy. Retries are only attempted if
 /// the `Error` returned by the future satisfies a given condition.
+///
+/// The condition can't extend the retry: once the strategy is exhausted, the last error is
+/// returned even if the condition would still retry it.
 #[pin_project]
 pub struct RetryIf<I, A, C, N>
 where
@@ -117,6 +811,40 @@ where
     condition: C,
     duration: Duration,
     notify: N,
+    last_error: Option<A::Error>,
+    attempts: usize,
+    safety_cap: Option<usize>,
+    advance_on_retry_after: bool,
+    resume: Option<Resume<I, A::Error>>,
+    on_retry: Option<OnRetry<A::Error>>,
+    admit: Option<Admit>,
+    park: Option<Park>,
+    permanent: bool,
+    handle: Option<Handle>,
+    sleep_started: Option<(Instant, Duration)>,
+    started: Instant,
+    last_retry_after: Option<Duration>,
+    error_log: Option<ErrorLog<A::Error>>,
+}
+
+impl<I, A, F> RetryIf<I, A, Decide<F>, NoopNotify>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+    F: FnMut(&A::Error) -> RetryDecision,
+{
+    /// Same as [`RetryIf::spawn`], with a three-way `decide` condition instead of a `bool`.
+    ///
+    /// [`RetryDecision::RetryNow`] behaves like a zero `retry_after`: the attempt is
+    /// retried right away, and the strategy is still advanced unless disabled with
+    /// [`RetryIf::advance_on_retry_after`], so retrying now still counts towards its limit.
+    pub fn spawn_decide<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        strategy: T,
+        action: A,
+        decide: F,
+    ) -> Self {
+        Self::spawn(strategy, action, Decide(decide), NoopNotify)
+    }
 }
 
 impl<I, A, C, N> RetryIf<I, A, C, N>
@@ -139,14 +867,102 @@ where
             condition,
             duration: Duration::from_millis(0),
             notify,
+            last_error: None,
+            attempts: 1,
+            safety_cap: None,
+            advance_on_retry_after: true,
+            resume: None,
+            on_retry: None,
+            admit: None,
+            park: None,
+            permanent: false,
+            handle: None,
+            sleep_started: None,
+            started: Instant::now(),
+            last_retry_after: None,
+            error_log: None,
         }
     }
 
+    /// The cap set by [`RetryIf::with_safety_cap`], large enough to never be reached by a
+    /// bounded strategy, but still ending a retry loop driven by an infinite one.
+    pub const DEFAULT_SAFETY_CAP: usize = 10_000;
+
+    /// Set a hard cap on the number of attempts, including the first one, guarding against
+    /// an infinite strategy, e.g. a `FixedInterval` missing a `.take(n)`.
+    ///
+    /// The cap is opt-in: it defaults to `None`, retrying for as long as the strategy
+    /// yields delays. Reaching it ends the retry with the last error, and is logged as an
+    /// error with the `tracing` feature, since it signals a missing bound.
+    #[must_use]
+    pub const fn safety_cap(mut self, cap: Option<usize>) -> Self {
+        self.safety_cap = cap;
+        self
+    }
+
+    /// Caps the number of attempts to [`RetryIf::DEFAULT_SAFETY_CAP`], the same as
+    /// `.safety_cap(Some(RetryIf::DEFAULT_SAFETY_CAP))`.
+    #[must_use]
+    pub const fn with_safety_cap(self) -> Self {
+        self.safety_cap(Some(Self::DEFAULT_SAFETY_CAP))
+    }
+
+    /// Whether the strategy is advanced when an error carries a `retry_after` duration.
+    ///
+    /// By default (`true`), a `retry_after` replaces the strategy delay but still consumes
+    /// a step of the strategy, and ends the retry if the strategy is exhausted. With `false`,
+    /// server-directed retries don't consume strategy steps, nor are they bounded by it.
+    ///
+    /// Since a server could then keep the retry going forever, `false` also sets
+    /// [`RetryIf::DEFAULT_SAFETY_CAP`] unless a cap is already set. The strategy is still
+    /// advanced whenever no safety cap is set, e.g. after a later `.safety_cap(None)`.
+    #[must_use]
+    pub const fn advance_on_retry_after(mut self, advance: bool) -> Self {
+        self.advance_on_retry_after = advance;
+        if !advance && self.safety_cap.is_none() {
+            self.safety_cap = Some(Self::DEFAULT_SAFETY_CAP);
+        }
+        self
+    }
+
+    /// Whether a `retry_after` replaces the strategy delay without advancing the strategy,
+    /// which is only allowed while the attempts are bounded by a safety cap.
+    const fn skips_strategy(&self, retry_after: Option<Duration>) -> bool {
+        retry_after.is_some() && !self.advance_on_retry_after && self.safety_cap.is_some()
+    }
+
+    /// Takes the last transient error that triggered a retry, if any.
+    fn take_last_error(self: Pin<&mut Self>) -> Option<A::Error> {
+        self.project().last_error.take()
+    }
+
+    fn unpark(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<A::Item, A::Error>> {
+        let this = self.as_mut().project();
+        let RetryStateProj::Parked { retry_after, .. } = this.state.project() else {
+            return Poll::Pending;
+        };
+        let retry_after = *retry_after;
+        if *this.park == Some(Park::Delay) {
+            let Some(err) = this.last_error.take() else {
+                return Poll::Pending;
+            };
+            return match self.schedule(err, retry_after, cx) {
+                Ok(poll) => poll,
+                Err(err) => Poll::Ready(Err(err)),
+            };
+        }
+        if let Some((start, requested)) = this.sleep_started.take() {
+            this.notify.slept(requested, start.elapsed());
+        }
+        self.attempt(cx)
+    }
+
     fn attempt(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Result<A::Item, A::Error>> {
         let future = {
             let mut this = self.as_mut().project();
             this.action.run()
         };
+        *self.as_mut().project().attempts += 1;
         self.as_mut()
             .project()
             .state
@@ -154,19 +970,106 @@ where
         self.poll(cx)
     }
 
+    fn transient(
+        mut self: Pin<&mut Self>,
+        err: A::Error,
+        retry_after: Option<Duration>,
+        cx: &mut Context,
+    ) -> Poll<Result<A::Item, A::Error>> {
+        if retry_after.is_some() {
+            *self.as_mut().project().last_retry_after = retry_after;
+        }
+        let retry_after = match self.as_mut().project().condition.decide(&err) {
+            RetryDecision::Stop => return Poll::Ready(Err(err)),
+            RetryDecision::Retry => retry_after,
+            RetryDecision::RetryNow => Some(Duration::ZERO),
+        };
+        let duration = retry_after.unwrap_or_else(|| *self.as_ref().project_ref().duration);
+        self.as_mut().project().notify.notify(&err, duration);
+        *self.as_mut().project().duration = duration;
+        match self.retry(err, retry_after, cx) {
+            Ok(poll) => poll,
+            Err(err) => Poll::Ready(Err(err)),
+        }
+    }
+
     fn retry(
         mut self: Pin<&mut Self>,
         err: A::Error,
+        retry_after: Option<Duration>,
         cx: &mut Context,
     ) -> Result<Poll<Result<A::Item, A::Error>>, A::Error> {
-        let Some(duration) = self.as_mut().project().strategy.next() else {
+        if self.safety_cap.is_some_and(|cap| self.attempts >= cap) {
             #[cfg(feature = "tracing")]
-            tracing::warn!("ending retry: strategy reached its limit");
+            tracing::error!(
+                attempts = self.attempts,
+                "ending retry: safety cap reached, is the strategy missing a bound?"
+            );
+            return Err(err);
+        }
+        if self.park == Some(Park::Delay) && !self.skips_strategy(retry_after) {
+            let mut this = self.as_mut().project();
+            *this.last_error = Some(err);
+            this.state.set(RetryState::Parked {
+                delay: Duration::ZERO,
+                retry_after,
+            });
+            return Ok(Poll::Pending);
+        }
+        self.schedule(err, retry_after, cx)
+    }
+
+    fn schedule(
+        mut self: Pin<&mut Self>,
+        err: A::Error,
+        retry_after: Option<Duration>,
+        cx: &mut Context,
+    ) -> Result<Poll<Result<A::Item, A::Error>>, A::Error> {
+        let duration = match retry_after {
+            Some(retry_after) if self.skips_strategy(Some(retry_after)) => retry_after,
+            _ => {
+                let Some(duration) = self.as_mut().project().strategy.next() else {
+                    #[cfg(feature = "tracing")]
+                    tracing::warn!("ending retry: strategy reached its limit");
+                    return Err(err);
+                };
+                retry_after.unwrap_or(duration)
+            }
+        };
+        if let Some(admit) = self.as_mut().project().admit
+            && !admit()
+        {
             return Err(err);
+        }
+        let total = accumulate(*self.as_ref().project_ref().duration, duration);
+        *self.as_mut().project().duration = total;
+        let mut this = self.as_mut().project();
+        if let Some(on_retry) = this.on_retry {
+            on_retry(*this.attempts, &err, duration);
+        }
+        match this.error_log {
+            Some(error_log) => error_log.push(err),
+            None => *this.last_error = Some(err),
+        }
+        if duration.is_zero() {
+            // same as `tokio::task::yield_now`, so zero delays don't starve other tasks
+            self.as_mut().project().state.set(RetryState::Yielding);
+            cx.waker().wake_by_ref();
+            return Ok(Poll::Pending);
+        }
+        *this.sleep_started = Some((Instant::now(), duration));
+        if *this.park == Some(Park::Sleep) {
+            this.state.set(RetryState::Parked {
+                delay: duration,
+                retry_after: None,
+            });
+            return Ok(Poll::Pending);
+        }
+        let deadline = deadline_after(duration);
+        let future = {
+            let _guard = this.handle.as_ref().map(Handle::enter);
+            sleep_until(deadline)
         };
-        *self.as_mut().project().duration += duration;
-        let deadline = Instant::now() + duration;
-        let future = sleep_until(deadline);
         self.as_mut()
             .project()
             .state
@@ -187,30 +1090,71 @@ where
     fn poll(mut self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
         match self.as_mut().project().state.poll(cx) {
             RetryFuturePoll::Running(poll_result) => match poll_result {
-                Poll::Ready(Ok(ok)) => Poll::Ready(Ok(ok)),
+                Poll::Ready(Ok(ok)) => {
+                    let this = self.as_mut().project();
+                    this.notify
+                        .succeeded(*this.attempts, this.started.elapsed());
+                    Poll::Ready(Ok(ok))
+                }
                 Poll::Pending => Poll::Pending,
                 Poll::Ready(Err(error)) => match error {
-                    RetryError::Permanent(err) => Poll::Ready(Err(err)),
-                    RetryError::Transient { err, retry_after } => {
-                        if self.as_mut().project().condition.should_retry(&err) {
-                            let duration = retry_after
-                                .unwrap_or_else(|| *self.as_ref().project_ref().duration);
-                            self.as_mut().project().notify.notify(&err, duration);
-                            *self.as_mut().project().duration = duration;
-                            match self.retry(err, cx) {
-                                Ok(poll) => poll,
-                                Err(err) => Poll::Ready(Err(err)),
-                            }
-                        } else {
-                            Poll::Ready(Err(err))
+                    RetryError::Permanent(err) => {
+                        let this = self.as_mut().project();
+                        let Some(strategy) = this.resume.as_mut().and_then(|resume| resume(&err))
+                        else {
+                            *this.permanent = true;
+                            return Poll::Ready(Err(err));
+                        };
+                        #[cfg(feature = "tracing")]
+                        tracing::warn!("resuming retry after a permanent error");
+                        *this.strategy = strategy;
+                        match self.retry(err, None, cx) {
+                            Ok(poll) => poll,
+                            Err(err) => Poll::Ready(Err(err)),
                         }
                     }
+                    RetryError::Transient { err, retry_after } => {
+                        self.transient(err, retry_after, cx)
+                    }
+                    RetryError::TransientAt { err, retry_at } => {
+                        let retry_after = retry_at.saturating_duration_since(Instant::now());
+                        self.transient(err, Some(retry_after), cx)
+                    }
                 },
             },
             RetryFuturePoll::Sleeping(poll_result) => match poll_result {
                 Poll::Pending => Poll::Pending,
-                Poll::Ready(()) => self.attempt(cx),
+                Poll::Ready(()) => {
+                    let mut this = self.as_mut().project();
+                    if let Some((start, requested)) = this.sleep_started.take() {
+                        this.notify.slept(requested, start.elapsed());
+                    }
+                    if *this.park == Some(Park::Attempt) {
+                        this.state.set(RetryState::Parked {
+                            delay: Duration::ZERO,
+                            retry_after: None,
+                        });
+                        return Poll::Pending;
+                    }
+                    self.attempt(cx)
+                }
             },
         }
     }
 }
+
+/// `total + elapsed`, saturating at `Duration::MAX` instead of panicking, for every
+/// cumulative delay or elapsed time tracked by the retry loops and strategies.
+pub const fn accumulate(total: Duration, elapsed: Duration) -> Duration {
+    total.saturating_add(elapsed)
+}
+
+/// `Instant::now() + duration`, saturating to a far future instant instead of panicking
+/// when `duration` overflows the clock. Past deadlines are computed with
+/// [`Instant::saturating_duration_since`], so they resolve to an immediate retry.
+pub fn deadline_after(duration: Duration) -> Instant {
+    let now = Instant::now();
+    now.checked_add(duration)
+        // same far future as `tokio::time`, roughly 30 years
+        .unwrap_or_else(|| now + Duration::from_secs(86400 * 365 * 30))
+}
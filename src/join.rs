@@ -0,0 +1,94 @@
+use std::{
+    future::Future,
+    iter::IntoIterator,
+    pin::Pin,
+    task::{Context, Poll},
+};
+
+use pin_project::pin_project;
+use tokio::time::Duration;
+
+use crate::{
+    action::Action,
+    future::Retry,
+    strategy::{MaxInterval, MaxIntervalIterator},
+};
+
+/// Retries several independent actions concurrently, each with a fresh strategy
+/// built by `strategy_factory`. Resolves once every action has resolved, with the
+/// results in the same order as `actions`.
+pub fn retry_join<S, T, A>(
+    mut strategy_factory: S,
+    actions: impl IntoIterator<Item = A>,
+) -> RetryJoin<T::IntoIter, A>
+where
+    S: FnMut() -> T,
+    T: IntoIterator<Item = Duration>,
+    A: Action,
+{
+    let retries: Vec<_> = actions
+        .into_iter()
+        .map(|action| Box::pin(Retry::spawn(strategy_factory(), action)))
+        .collect();
+    RetryJoin {
+        results: retries.iter().map(|_| None).collect(),
+        retries,
+    }
+}
+
+/// Same as [`retry_join`], but all actions share a `max_elapsed` budget: once it is
+/// spent no action is retried anymore, and each resolves with its last error.
+pub fn retry_join_within<S, T, A>(
+    mut strategy_factory: S,
+    actions: impl IntoIterator<Item = A>,
+    max_elapsed: Duration,
+) -> RetryJoin<MaxIntervalIterator<T::IntoIter>, A>
+where
+    S: FnMut() -> T,
+    T: IntoIterator<Item = Duration>,
+    A: Action,
+{
+    retry_join(
+        move || strategy_factory().into_iter().max_duration(max_elapsed),
+        actions,
+    )
+}
+
+/// Future that drives several retries concurrently,
+/// created by [`retry_join`] and [`retry_join_within`] functions.
+#[pin_project]
+pub struct RetryJoin<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    retries: Vec<Pin<Box<Retry<I, A>>>>,
+    results: Vec<Option<Result<A::Item, A::Error>>>,
+}
+
+impl<I, A> Future for RetryJoin<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    type Output = Vec<Result<A::Item, A::Error>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let this = self.project();
+        let mut done = true;
+        for (retry, result) in this.retries.iter_mut().zip(this.results.iter_mut()) {
+            if result.is_none() {
+                match retry.as_mut().poll(cx) {
+                    Poll::Ready(output) => *result = Some(output),
+                    Poll::Pending => done = false,
+                }
+            }
+        }
+
+        if done {
+            Poll::Ready(this.results.iter_mut().filter_map(Option::take).collect())
+        } else {
+            Poll::Pending
+        }
+    }
+}
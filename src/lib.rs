@@ -13,7 +13,7 @@
 //! This library provides extensible asynchronous retry behaviors
 //! for use with the ecosystem of [`tokio`](https://tokio.rs/) libraries.
 //!
-//! There are 4 backoff strategies:
+//! There are 6 backoff strategies:
 //! - `ExponentialBackoff`: base is considered the initial retry interval, so if defined from 500ms, the next retry will happen at 250000ms.
 //!     | attempt | delay |
 //!     |---------|-------|
@@ -27,6 +27,13 @@
 //!     | 3       | 2000ms|
 //!     | 4       | 4000ms|
 //!
+//! - `ExponentialRationalBackoff`: same as `ExponentialFactorBackoff`, but the factor is an integer ratio `numerator / denominator`, so delays are reproducible bit-for-bit. With a ratio of `3 / 2` from 500ms:
+//!     | attempt | delay |
+//!     |---------|-------|
+//!     | 1       | 500ms |
+//!     | 2       | 750ms |
+//!     | 3       | 1125ms|
+//!
 //! - `FixedInterval`: in this backoff strategy, a fixed interval is used as constant. So if defined from 500ms, all attempts will happen at 500ms.
 //!     | attempt | delay |
 //!     |---------|-------|
@@ -46,6 +53,12 @@
 //!     | 1       | 500ms|
 //!     | 2       | 600ms|
 //!     | 3       | 700ms|
+//! - `RtoBackoff`: TCP retransmission timeout backoff. The delay doubles on every retry, clamped between a minimum (200ms) and a maximum (120s). So if defined from 1s:
+//!     | attempt | delay |
+//!     |---------|-------|
+//!     | 1       | 1s|
+//!     | 2       | 2s|
+//!     | 3       | 4s|
 //!
 //! > All strategies can be jittered with the `jitter` feature.
 //!
@@ -124,7 +137,13 @@
 //! ## Features
 //! `[jitter]`
 //! - `jitter` ranges between 50% and 150% of the strategy delay.
+//! - `jitter_fast` same as `jitter`, using a cheaper non-cryptographic thread-local generator.
 //! - `jitter_with_bounds(min: f64, max: f64)` ranges between `min * Duration` and `max * Duration`.
+//! - `jitter_floored(duration: Duration, floor: Duration)` same as `jitter`, but never below `floor`.
+//! - `jitter_down(min_factor: f64)` ranges between `min_factor * Duration` and `Duration`, never exceeding the strategy delay.
+//! - `jitter_from_hash(duration: Duration, seed: impl Hash)` same as `jitter`, but always the same delay for the same `seed`.
+//! - `jitter_capped(base_fn, max_abs: Duration)` same as `base_fn`, but never deviating from the strategy delay by more than `max_abs`.
+//! - `jitter_above(threshold: Duration, inner)` same as `inner`, but only for delays above `threshold`, leaving smaller ones unchanged.
 //!
 //! To use jitter, add this to your Cargo.toml
 //!
@@ -189,15 +208,45 @@
 #![allow(warnings)]
 
 mod action;
+mod async_strategy;
+mod bounded;
+mod budget;
+mod compat;
 mod condition;
 pub(crate) mod error;
 mod future;
+mod join;
 mod notify;
+mod returning;
+mod runner;
+#[cfg(feature = "sleeper")]
+pub mod sleeper;
 /// Assorted retry strategies including fixed interval and exponential back-off.
 pub mod strategy;
+mod stream;
+mod window;
 
-pub use action::Action;
-pub use condition::Condition;
-pub use error::{Error as RetryError, MapErr};
-pub use future::{Retry, RetryIf};
-pub use notify::Notify;
+#[cfg(feature = "tracing")]
+pub use action::TracedAction;
+pub use action::{
+    Action, AsyncFnAction, AttemptTimeout, AttemptTimeoutFuture, BlockingAction, BlockingFuture,
+    CatchUnwind, CatchUnwindFuture, StatefulAction,
+};
+pub use async_strategy::RetryAsyncStrategy;
+pub use bounded::{RetryBounded, StopReason};
+pub use budget::RetryBudget;
+pub use compat::{retry, retry_if};
+pub use condition::{Condition, Decide, MaxRepeats, RetryDecision};
+pub use error::{Error as RetryError, MapErr, WithReason};
+pub use future::{
+    BoxedRetry, Retry, RetryCollectErrors, RetryDetailed, RetryFailure, RetryIf, RetryOutcome,
+    RetryProgress, RetryTimed, RetryUntilSignal, RetryWithTimeout,
+};
+pub use join::{RetryJoin, retry_join, retry_join_within};
+pub use notify::{ChainNotify, FnNotify, NoopNotify, Notify};
+pub use returning::RetryReturning;
+pub use runner::RetryRunner;
+pub use stream::{
+    ChunkStream, ResumableStream, ResumableStreamFuture, StrategyStream, strategy_stream,
+};
+pub use window::RetryIfAllowed;
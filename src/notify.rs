@@ -2,6 +2,32 @@ use std::time::Duration;
 
 pub trait Notify<E> {
     fn notify(&mut self, err: &E, duration: Duration);
+
+    /// Called after each sleep between attempts with the `requested` delay and the
+    /// `actual` time slept, which timer resolution and scheduler load make longer.
+    /// Does nothing by default.
+    fn slept(&mut self, requested: Duration, actual: Duration) {
+        let _ = (requested, actual);
+    }
+
+    /// Called once the action succeeds with the number of `attempts`, counting the first
+    /// one, and the `elapsed` time since the retry was spawned, e.g. to record that an
+    /// operation only succeeded after retrying. Does nothing by default.
+    fn succeeded(&mut self, attempts: usize, elapsed: Duration) {
+        let _ = (attempts, elapsed);
+    }
+
+    /// Forwards each notification to `self`, then to `next`,
+    /// e.g. to both log and record metrics on each retry.
+    fn and_then<N: Notify<E>>(self, next: N) -> ChainNotify<Self, N>
+    where
+        Self: Sized,
+    {
+        ChainNotify {
+            first: self,
+            second: next,
+        }
+    }
 }
 
 impl<E, F> Notify<E> for F
@@ -17,13 +43,72 @@ impl<E> Notify<E> for Box<dyn Notify<E>> {
     fn notify(&mut self, err: &E, duration: Duration) {
         (**self).notify(err, duration);
     }
+
+    fn slept(&mut self, requested: Duration, actual: Duration) {
+        (**self).slept(requested, actual);
+    }
+
+    fn succeeded(&mut self, attempts: usize, elapsed: Duration) {
+        (**self).succeeded(attempts, elapsed);
+    }
 }
 
 /// A notify implementation that does nothing
-pub struct EmptyNotify;
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoopNotify;
 
-impl<E> Notify<E> for EmptyNotify {
+impl<E> Notify<E> for NoopNotify {
     fn notify(&mut self, _err: &E, _duration: Duration) {
         // Do nothing
     }
 }
+
+/// A notify implementation wrapping a closure, useful to build a `Box<dyn Notify<E>>`
+/// from a closure.
+#[derive(Debug, Clone, Copy)]
+pub struct FnNotify<F>(pub F);
+
+impl<F> FnNotify<F> {
+    /// Wraps `f` as a notify implementation.
+    pub const fn new(f: F) -> Self {
+        Self(f)
+    }
+}
+
+impl<E, F> Notify<E> for FnNotify<F>
+where
+    F: FnMut(&E, Duration),
+{
+    fn notify(&mut self, err: &E, duration: Duration) {
+        (self.0)(err, duration);
+    }
+}
+
+/// A notify implementation forwarding to two handlers in order,
+/// created by [`Notify::and_then`] function.
+#[derive(Debug, Clone, Copy)]
+pub struct ChainNotify<A, B> {
+    first: A,
+    second: B,
+}
+
+impl<E, A, B> Notify<E> for ChainNotify<A, B>
+where
+    A: Notify<E>,
+    B: Notify<E>,
+{
+    fn notify(&mut self, err: &E, duration: Duration) {
+        self.first.notify(err, duration);
+        self.second.notify(err, duration);
+    }
+
+    fn slept(&mut self, requested: Duration, actual: Duration) {
+        self.first.slept(requested, actual);
+        self.second.slept(requested, actual);
+    }
+
+    fn succeeded(&mut self, attempts: usize, elapsed: Duration) {
+        self.first.succeeded(attempts, elapsed);
+        self.second.succeeded(attempts, elapsed);
+    }
+}
@@ -0,0 +1,82 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project::pin_project;
+use tokio::time::Duration;
+
+use crate::{action::Action, future::Retry};
+
+impl<I, A> Retry<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    /// Same as [`Retry::spawn`], but resolves to the result along with the strategy,
+    /// so a stateful strategy can be continued by a later operation from where this
+    /// one left off.
+    ///
+    /// The strategy is handed back as is, with every delay slept by this retry consumed.
+    pub fn spawn_returning<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        strategy: T,
+        action: A,
+    ) -> RetryReturning<I, A> {
+        RetryReturning::new(strategy.into_iter(), action)
+    }
+}
+
+/// The strategy of the underlying retry loop, handing the wrapped strategy back once done.
+struct Handback<I>(Option<I>);
+
+impl<I: Iterator<Item = Duration>> Iterator for Handback<I> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        self.0.as_mut()?.next()
+    }
+}
+
+/// Future that drives multiple attempts at an action via a retry strategy, handing the
+/// strategy back once done, created by [`Retry::spawn_returning`] function.
+#[pin_project]
+pub struct RetryReturning<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    // the strategy is only taken out once the future resolved
+    #[pin]
+    retry: Retry<Handback<I>, A>,
+}
+
+impl<I, A> RetryReturning<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    fn new(strategy: I, action: A) -> Self {
+        Self {
+            retry: Retry::spawn(Handback(Some(strategy)), action),
+        }
+    }
+}
+
+impl<I, A> Future for RetryReturning<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    type Output = (Result<A::Item, A::Error>, I);
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut this = self.project();
+        let result = ready!(this.retry.as_mut().poll(cx));
+        this.retry
+            .strategy_mut()
+            .0
+            .take()
+            .map_or(Poll::Pending, |strategy| Poll::Ready((result, strategy)))
+    }
+}
@@ -0,0 +1,41 @@
+use std::iter::IntoIterator;
+
+use tokio::time::Duration;
+
+use crate::{action::Action, future::Retry};
+
+/// A reusable retry configuration, running actions with a fresh copy of its strategy
+/// on every [`RetryRunner::run`] call.
+///
+/// ```
+/// # use tokio_retry2::{RetryError, RetryRunner};
+/// # use tokio_retry2::strategy::ExponentialBackoff;
+/// # #[tokio::main]
+/// # async fn main() {
+/// let runner = RetryRunner::new(ExponentialBackoff::from_millis(10).take(3));
+///
+/// for _ in 0..2 {
+///     let result = runner.run(|| async { Ok::<_, RetryError<()>>(42) }).await;
+///     assert_eq!(result, Ok(42));
+/// }
+/// # }
+/// ```
+#[derive(Debug, Clone)]
+pub struct RetryRunner<S> {
+    strategy: S,
+}
+
+impl<S> RetryRunner<S>
+where
+    S: IntoIterator<Item = Duration> + Clone,
+{
+    /// Creates a runner, cloning `strategy` for each run.
+    pub const fn new(strategy: S) -> Self {
+        Self { strategy }
+    }
+
+    /// Retries `action` with the full strategy, same as [`Retry::spawn`].
+    pub fn run<A: Action>(&self, action: A) -> Retry<S::IntoIter, A> {
+        Retry::spawn(self.strategy.clone(), action)
+    }
+}
@@ -0,0 +1,133 @@
+//! Pluggable timers, to wait between attempts without the tokio timer, e.g. with the
+//! timer of another runtime, or a test timer.
+//!
+//! [`Retry::spawn_with_sleeper`] drives the same retry loop as [`Retry::spawn`], but
+//! waits between attempts with the supplied [`Sleeper`] instead of the tokio timer.
+//!
+//! This is not enough to run on `wasm32-unknown-unknown`: the retry loop, this one
+//! included, still reads the clock through [`tokio::time::Instant`].
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, ready},
+};
+
+use pin_project::pin_project;
+use tokio::time::Duration;
+
+use crate::{
+    action::Action,
+    future::{Park, Retry},
+};
+
+/// Waits between attempts, see the [module documentation](self).
+pub trait Sleeper {
+    /// The future returned by [`Sleeper::sleep`].
+    type Sleep: Future<Output = ()>;
+
+    /// Returns a future completing after `duration`.
+    fn sleep(&self, duration: Duration) -> Self::Sleep;
+}
+
+impl<F, Fut> Sleeper for F
+where
+    F: Fn(Duration) -> Fut,
+    Fut: Future<Output = ()>,
+{
+    type Sleep = Fut;
+
+    fn sleep(&self, duration: Duration) -> Self::Sleep {
+        self(duration)
+    }
+}
+
+/// [`Sleeper`] backed by [`tokio::time::sleep`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioSleeper;
+
+impl Sleeper for TokioSleeper {
+    type Sleep = tokio::time::Sleep;
+
+    fn sleep(&self, duration: Duration) -> Self::Sleep {
+        tokio::time::sleep(duration)
+    }
+}
+
+impl<I, A> Retry<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    /// Same as [`Retry::spawn`], but waits between attempts with `sleeper`.
+    ///
+    /// A `retry_after` or `retry_at` carried by a transient error replaces the delay
+    /// from the strategy, as with [`Retry::spawn`]. Zero delays still yield to the
+    /// scheduler instead of calling `sleeper`.
+    pub fn spawn_with_sleeper<T, S>(strategy: T, action: A, sleeper: S) -> RetryWithSleeper<I, A, S>
+    where
+        T: IntoIterator<IntoIter = I, Item = Duration>,
+        S: Sleeper,
+    {
+        RetryWithSleeper::new(strategy.into_iter(), action, sleeper)
+    }
+}
+
+/// Future that drives multiple attempts at an action via a retry strategy and a
+/// [`Sleeper`], created by [`Retry::spawn_with_sleeper`] function.
+#[pin_project]
+pub struct RetryWithSleeper<I, A, S>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+    S: Sleeper,
+{
+    #[pin]
+    retry: Retry<I, A>,
+    sleeper: S,
+    #[pin]
+    sleep: Option<S::Sleep>,
+}
+
+impl<I, A, S> RetryWithSleeper<I, A, S>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+    S: Sleeper,
+{
+    fn new(strategy: I, action: A, sleeper: S) -> Self {
+        Self {
+            retry: Retry::spawn(strategy, action).park_at(Park::Sleep),
+            sleeper,
+            sleep: None,
+        }
+    }
+}
+
+impl<I, A, S> Future for RetryWithSleeper<I, A, S>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+    S: Sleeper,
+{
+    type Output = Result<A::Item, A::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            let poll = if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+                ready!(sleep.poll(cx));
+                this.sleep.set(None);
+                this.retry.as_mut().unpark(cx)
+            } else {
+                this.retry.as_mut().poll(cx)
+            };
+            if poll.is_ready() {
+                return poll;
+            }
+            let Some(delay) = this.retry.parked() else {
+                return Poll::Pending;
+            };
+            this.sleep.set(Some(this.sleeper.sleep(delay)));
+        }
+    }
+}
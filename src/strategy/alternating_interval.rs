@@ -0,0 +1,93 @@
+use tokio::time::Duration;
+
+/// A retry strategy cycling through a list of intervals indefinitely, e.g. alternating
+/// short and long polling intervals.
+///
+/// A one-element list behaves like [`FixedInterval`](crate::strategy::FixedInterval),
+/// and an empty list yields no delay, so the action is never retried.
+///
+/// ```
+/// use std::time::Duration;
+/// use tokio_retry2::strategy::AlternatingInterval;
+///
+/// let mut strategy = AlternatingInterval::new(&[Duration::from_secs(1), Duration::from_secs(10)]);
+///
+/// assert_eq!(strategy.next(), Some(Duration::from_secs(1)));
+/// assert_eq!(strategy.next(), Some(Duration::from_secs(10)));
+/// assert_eq!(strategy.next(), Some(Duration::from_secs(1)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AlternatingInterval {
+    intervals: Vec<Duration>,
+    index: usize,
+}
+
+impl AlternatingInterval {
+    /// Constructs a new strategy cycling through `intervals`.
+    #[must_use]
+    pub fn new(intervals: &[Duration]) -> Self {
+        Self {
+            intervals: intervals.to_vec(),
+            index: 0,
+        }
+    }
+
+    /// Constructs a new strategy cycling through `intervals` in milliseconds.
+    #[must_use]
+    pub fn from_millis(intervals: &[u64]) -> Self {
+        Self {
+            intervals: intervals
+                .iter()
+                .copied()
+                .map(Duration::from_millis)
+                .collect(),
+            index: 0,
+        }
+    }
+
+    /// The intervals cycled through.
+    #[must_use]
+    pub fn intervals(&self) -> &[Duration] {
+        &self.intervals
+    }
+}
+
+impl Iterator for AlternatingInterval {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        let delay = *self.intervals.get(self.index)?;
+        self.index = (self.index + 1) % self.intervals.len();
+        Some(delay)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.intervals.is_empty() {
+            (0, Some(0))
+        } else {
+            (usize::MAX, None)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn cycles_through_intervals() {
+        let s = AlternatingInterval::from_millis(&[1_000, 10_000]).take(6);
+
+        assert!(s.eq([1, 10, 1, 10, 1, 10].map(Duration::from_secs)));
+    }
+
+    #[test]
+    fn single_and_empty_intervals() {
+        let s = AlternatingInterval::new(&[Duration::from_millis(5)]).take(3);
+        assert!(s.eq([Duration::from_millis(5); 3]));
+
+        let mut s = AlternatingInterval::new(&[]);
+        assert_eq!(s.next(), None);
+        assert_eq!(s.size_hint(), (0, Some(0)));
+    }
+}
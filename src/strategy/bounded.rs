@@ -0,0 +1,92 @@
+use tokio::time::Duration;
+
+/// A strategy wrapper yielding at most a given number of delays.
+///
+/// Created by [`ExponentialBackoff::with_max_retries`](crate::strategy::ExponentialBackoff::with_max_retries)
+/// and [`FibonacciBackoff::with_max_retries`](crate::strategy::FibonacciBackoff::with_max_retries)
+/// functions. Same as [`Iterator::take`], but with a nameable type owned by the crate.
+#[derive(Debug, Clone)]
+pub struct Bounded<I> {
+    iter: I,
+    remaining: usize,
+}
+
+impl<I: Iterator<Item = Duration>> Bounded<I> {
+    /// Wraps any strategy, so that it yields at most `max_retries` delays.
+    pub const fn new(iter: I, max_retries: usize) -> Self {
+        Self {
+            iter,
+            remaining: max_retries,
+        }
+    }
+
+    /// The number of delays left before the strategy stops retrying.
+    pub const fn remaining(&self) -> usize {
+        self.remaining
+    }
+
+    /// Applies [`jitter`](crate::strategy::jitter) to every delay, keeping the bound.
+    #[cfg(feature = "jitter")]
+    #[must_use]
+    pub fn jitter(self) -> Bounded<std::iter::Map<I, fn(Duration) -> Duration>> {
+        Bounded {
+            iter: self.iter.map(super::jitter),
+            remaining: self.remaining,
+        }
+    }
+}
+
+impl<I: Iterator<Item = Duration>> Iterator for Bounded<I> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("max retries for strategy reached");
+            return None;
+        }
+        self.remaining -= 1;
+        self.iter.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.min(self.remaining),
+            Some(upper.map_or(self.remaining, |upper| upper.min(self.remaining))),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::FixedInterval;
+
+    #[test]
+    fn yields_at_most_max_retries() {
+        let mut s = Bounded::new(FixedInterval::from_millis(10), 2);
+
+        assert_eq!(s.remaining(), 2);
+        assert_eq!(s.next(), Some(Duration::from_millis(10)));
+        assert_eq!(s.next(), Some(Duration::from_millis(10)));
+        assert_eq!(s.next(), None);
+        assert_eq!(s.remaining(), 0);
+    }
+
+    #[test]
+    fn ends_with_shorter_strategy() {
+        let s = Bounded::new(FixedInterval::from_millis(10).take(1), 3);
+
+        assert_eq!(s.count(), 1);
+    }
+
+    #[cfg(feature = "jitter")]
+    #[test]
+    fn jitter_keeps_the_bound() {
+        let mut s = Bounded::new(FixedInterval::from_millis(10), 3).jitter();
+
+        assert_eq!(s.remaining(), 3);
+        assert!(s.all(|d| d >= Duration::from_millis(5) && d < Duration::from_millis(15)));
+    }
+}
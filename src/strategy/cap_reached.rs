@@ -0,0 +1,115 @@
+use tokio::time::Duration;
+
+use super::{
+    ExponentialBackoff, ExponentialFactorBackoff, ExponentialRationalBackoff, FibonacciBackoff,
+    LinearBackoff, RtoBackoff,
+};
+
+/// Strategies clamping their delays to a maximum delay.
+pub trait CappedStrategy: Iterator<Item = Duration> {
+    /// The maximum delay of the strategy, if any.
+    fn max_delay_cap(&self) -> Option<Duration>;
+
+    /// Calls `f` once, with the 1-based index of the delay, the first time a delay
+    /// reaches the maximum delay, e.g. to log it once instead of on every clamped delay.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tokio_retry2::strategy::{CappedStrategy, ExponentialBackoff};
+    ///
+    /// let strategy = ExponentialBackoff::from_millis(10)
+    ///     .max_delay_millis(500)
+    ///     .on_cap_reached(|attempt| println!("delay capped from attempt {attempt}"));
+    /// # assert_eq!(strategy.take(3).last(), Some(Duration::from_millis(500)));
+    /// ```
+    fn on_cap_reached<F: FnOnce(usize)>(self, f: F) -> OnCapReached<Self, F>
+    where
+        Self: Sized,
+    {
+        OnCapReached {
+            iter: self,
+            f: Some(f),
+            yielded: 0,
+        }
+    }
+}
+
+macro_rules! impl_capped_strategy {
+    ($($strategy:ty),*) => {
+        $(impl CappedStrategy for $strategy {
+            // the inherent getter of the same name
+            fn max_delay_cap(&self) -> Option<Duration> {
+                <$strategy>::max_delay_cap(self)
+            }
+        })*
+    };
+}
+
+impl_capped_strategy!(
+    ExponentialBackoff,
+    ExponentialFactorBackoff,
+    ExponentialRationalBackoff,
+    FibonacciBackoff,
+    LinearBackoff,
+    RtoBackoff
+);
+
+/// A strategy wrapper calling a closure once the maximum delay is reached,
+/// created by [`CappedStrategy::on_cap_reached`] function.
+#[derive(Debug, Clone)]
+pub struct OnCapReached<I, F> {
+    iter: I,
+    f: Option<F>,
+    yielded: usize,
+}
+
+impl<I: CappedStrategy, F: FnOnce(usize)> Iterator for OnCapReached<I, F> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let cap = self.iter.max_delay_cap();
+        let delay = self.iter.next()?;
+        self.yielded = self.yielded.saturating_add(1);
+        if cap.is_some_and(|cap| delay >= cap)
+            && let Some(f) = self.f.take()
+        {
+            f(self.yielded);
+        }
+        Some(delay)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::RefCell;
+
+    use super::*;
+
+    #[test]
+    fn fires_once_when_cap_is_first_reached() {
+        let reached = RefCell::new(Vec::new());
+        let delays: Vec<_> = FibonacciBackoff::from_millis(10)
+            .max_delay_millis(25)
+            .on_cap_reached(|attempt| reached.borrow_mut().push(attempt))
+            .take(6)
+            .collect();
+
+        assert_eq!(delays, [10, 10, 20, 25, 25, 25].map(Duration::from_millis));
+        assert_eq!(*reached.borrow(), [4]);
+    }
+
+    #[test]
+    fn never_fires_without_cap() {
+        let mut reached = false;
+        ExponentialBackoff::from_millis(10)
+            .on_cap_reached(|_| reached = true)
+            .take(5)
+            .for_each(drop);
+
+        assert!(!reached);
+    }
+}
@@ -0,0 +1,98 @@
+use tokio::time::Duration;
+
+/// An object-safe strategy, for storing heterogeneous strategies as `Box<dyn DynStrategy>`
+/// while still being able to clone fresh iterators from them.
+///
+/// It is implemented for every `Clone` strategy, and `Box<dyn DynStrategy>` is itself a
+/// strategy, so it can be passed to `Retry::spawn`.
+///
+/// ```
+/// use std::time::Duration;
+/// use tokio_retry2::strategy::{DynStrategy, ExponentialBackoff, FixedInterval};
+///
+/// let strategies: Vec<Box<dyn DynStrategy>> = vec![
+///     Box::new(FixedInterval::from_millis(10).take(2)),
+///     Box::new(ExponentialBackoff::from_millis(10).take(2)),
+/// ];
+///
+/// let fresh = strategies[1].clone();
+/// assert_eq!(fresh.collect::<Vec<_>>(), [10, 100].map(Duration::from_millis));
+/// ```
+pub trait DynStrategy: Send {
+    /// Yields the next delay, same as `Iterator::next`.
+    fn next_delay(&mut self) -> Option<Duration>;
+
+    /// Clones the strategy, in its current state, into a new box.
+    fn box_clone(&self) -> Box<dyn DynStrategy>;
+
+    /// The bounds on the remaining delays, same as `Iterator::size_hint`.
+    fn delays_hint(&self) -> (usize, Option<usize>) {
+        (0, None)
+    }
+}
+
+impl<T> DynStrategy for T
+where
+    T: Iterator<Item = Duration> + Clone + Send + 'static,
+{
+    fn next_delay(&mut self) -> Option<Duration> {
+        self.next()
+    }
+
+    fn box_clone(&self) -> Box<dyn DynStrategy> {
+        Box::new(self.clone())
+    }
+
+    fn delays_hint(&self) -> (usize, Option<usize>) {
+        self.size_hint()
+    }
+}
+
+impl Iterator for Box<dyn DynStrategy> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        (**self).next_delay()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (**self).delays_hint()
+    }
+}
+
+impl Clone for Box<dyn DynStrategy> {
+    fn clone(&self) -> Self {
+        (**self).box_clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::{FibonacciBackoff, FixedInterval};
+
+    #[test]
+    fn stores_heterogeneous_strategies() {
+        let strategies: Vec<Box<dyn DynStrategy>> = vec![
+            Box::new(FixedInterval::from_millis(10).take(3)),
+            Box::new(FibonacciBackoff::from_millis(10).take(4)),
+        ];
+
+        for _ in 0..2 {
+            let delays: Vec<Vec<_>> = strategies.iter().map(|s| s.clone().collect()).collect();
+            assert_eq!(delays[0], [10, 10, 10].map(Duration::from_millis));
+            assert_eq!(delays[1], [10, 10, 20, 30].map(Duration::from_millis));
+        }
+    }
+
+    #[test]
+    fn box_clone_keeps_current_state() {
+        let mut s: Box<dyn DynStrategy> = Box::new(FibonacciBackoff::from_millis(10));
+        s.next_delay();
+        s.next_delay();
+
+        let mut cloned = s.box_clone();
+        assert_eq!(cloned.next_delay(), Some(Duration::from_millis(20)));
+        assert_eq!(s.next_delay(), Some(Duration::from_millis(20)));
+    }
+}
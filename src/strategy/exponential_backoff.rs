@@ -2,11 +2,16 @@ use std::iter::Iterator;
 
 use tokio::time::Duration;
 
+use super::{Bounded, DurableStrategy, ExponentialFactorBackoff};
+use crate::future::accumulate;
+
 /// A retry strategy driven by exponential back-off.
 ///
 /// The power corresponds to the number of past attempts.
 #[derive(Debug, Clone)]
 pub struct ExponentialBackoff {
+    // the delay of the first attempt, before the factor is applied
+    initial: u64,
     current: u64,
     base: u64,
     factor: u64,
@@ -22,6 +27,7 @@ impl ExponentialBackoff {
     #[must_use]
     pub const fn from_millis(base: u64) -> Self {
         Self {
+            initial: base,
             current: base,
             base,
             factor: 1u64,
@@ -29,14 +35,54 @@ impl ExponentialBackoff {
         }
     }
 
+    /// Constructs an exponential back-off strategy from `start` to `end` over `attempts`
+    /// delays, computing the geometric ratio fitting both endpoints.
+    ///
+    /// An integer base can't fit most endpoints, so the strategy is an
+    /// [`ExponentialFactorBackoff`] with the exact ratio as its base factor, capped at
+    /// `end`. Delays are truncated to milliseconds: `start` is clamped to at least `1ms`,
+    /// and the last delay may fall up to a millisecond short of `end`.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tokio_retry2::strategy::ExponentialBackoff;
+    ///
+    /// // ratio of 300^(1/7) ~ 2.26
+    /// let strategy = ExponentialBackoff::fit(Duration::from_millis(100), Duration::from_secs(30), 8);
+    ///
+    /// let delays: Vec<_> = strategy.map(|delay| delay.as_millis()).collect();
+    /// assert_eq!(delays, [100, 225, 510, 1152, 2603, 5879, 13281, 30000]);
+    /// ```
+    #[must_use]
+    pub fn fit(
+        start: Duration,
+        end: Duration,
+        attempts: usize,
+    ) -> Bounded<ExponentialFactorBackoff> {
+        let start_millis = u64::try_from(start.as_millis()).unwrap_or(u64::MAX).max(1);
+        let start = Duration::from_millis(start_millis);
+        let ratio = if attempts < 2 || end <= start {
+            1.0
+        } else {
+            #[expect(clippy::cast_precision_loss, reason = "attempts far below 2^52")]
+            let steps = (attempts - 1) as f64;
+            (end.as_secs_f64() / start.as_secs_f64()).powf(steps.recip())
+        };
+        Bounded::new(
+            ExponentialFactorBackoff::from_millis(start_millis, ratio).max_delay(end),
+            attempts,
+        )
+    }
+
     /// A multiplicative factor that will be applied to the retry delay.
     ///
     /// For example, using a factor of `1000` will make each delay in units of seconds.
     ///
-    /// Default factor is `1`.
+    /// Default factor is `1`. A factor of `0` would turn every delay into a tight retry
+    /// loop, so it is clamped to `1`.
     #[must_use]
     pub const fn factor(mut self, factor: u64) -> Self {
-        self.factor = factor;
+        self.factor = if factor == 0 { 1 } else { factor };
         self
     }
 
@@ -53,26 +99,112 @@ impl ExponentialBackoff {
         self.max_delay = Some(Duration::from_millis(duration));
         self
     }
+
+    /// Advances the strategy as if `n` delays were already yielded, so the first delay is
+    /// the one of attempt `n + 1`. Useful to resume a persisted retry state.
+    #[must_use]
+    pub fn starting_at_attempt(mut self, n: u64) -> Self {
+        let exp = u32::try_from(n).unwrap_or(u32::MAX);
+        self.current = match self.base.checked_pow(exp) {
+            Some(power) => self.initial.saturating_mul(power),
+            None if self.initial == 0 => 0,
+            None => u64::MAX,
+        };
+        self
+    }
+
+    /// Limits the strategy to `max_retries` delays, same as `.take(max_retries)`,
+    /// but with a nameable type.
+    ///
+    /// ```
+    /// use tokio_retry2::strategy::{Bounded, ExponentialBackoff};
+    ///
+    /// fn strategy() -> Bounded<ExponentialBackoff> {
+    ///     ExponentialBackoff::from_millis(10).with_max_retries(3)
+    /// }
+    ///
+    /// assert_eq!(strategy().count(), 3);
+    /// ```
+    #[must_use]
+    pub const fn with_max_retries(self, max_retries: usize) -> Bounded<Self> {
+        Bounded::new(self, max_retries)
+    }
+
+    /// Same as [`ExponentialBackoff::with_max_retries`], also returning the worst-case
+    /// total delay over the `attempts` retries, e.g. to reject a policy exceeding an SLA.
+    /// The total saturates at `Duration::MAX`.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tokio_retry2::strategy::ExponentialBackoff;
+    ///
+    /// let (strategy, total) = ExponentialBackoff::from_millis(10)
+    ///     .max_delay_millis(500)
+    ///     .build_with_total(3);
+    ///
+    /// assert_eq!(total, Duration::from_millis(610));
+    /// assert_eq!(strategy.count(), 3);
+    /// ```
+    #[must_use]
+    pub fn build_with_total(self, attempts: usize) -> (Bounded<Self>, Duration) {
+        let strategy = self.with_max_retries(attempts);
+        let total = strategy.clone().fold(Duration::ZERO, accumulate);
+        (strategy, total)
+    }
+
+    /// The first delay, with the factor applied, before the maximum delay cap.
+    #[must_use]
+    pub const fn first_delay(&self) -> Duration {
+        Duration::from_millis(self.initial.saturating_mul(self.factor))
+    }
+
+    /// The base raised to the power of the number of past attempts, as given to
+    /// [`ExponentialBackoff::from_millis`].
+    #[must_use]
+    pub const fn base(&self) -> u64 {
+        self.base
+    }
+
+    /// The multiplicative factor applied to each delay.
+    #[must_use]
+    pub const fn delay_factor(&self) -> u64 {
+        self.factor
+    }
+
+    /// The maximum delay cap, if any.
+    #[must_use]
+    pub const fn max_delay_cap(&self) -> Option<Duration> {
+        self.max_delay
+    }
+}
+
+/// Defaults to a base of `2` with a factor of `100`, without max delay,
+/// so delays are `200ms`, `400ms`, `800ms`, ...
+impl Default for ExponentialBackoff {
+    fn default() -> Self {
+        Self::from_millis(2).factor(100)
+    }
 }
 
 impl Iterator for ExponentialBackoff {
     type Item = Duration;
 
     fn next(&mut self) -> Option<Duration> {
-        // set delay duration by applying factor
+        // set delay duration by applying factor, `None` on overflow
         let duration = self
             .current
             .checked_mul(self.factor)
-            .map_or_else(|| Duration::from_millis(u64::MAX), Duration::from_millis);
+            .map(Duration::from_millis);
 
-        // check if we reached max delay
-        if let Some(ref max_delay) = self.max_delay
-            && duration > *max_delay
+        // check if we reached max delay, an overflowing delay always exceeds it
+        if let Some(max_delay) = self.max_delay
+            && duration.is_none_or(|duration| duration > max_delay)
         {
             #[cfg(feature = "tracing")]
-            tracing::warn!("`max_delay` for strategy reached");
-            return Some(*max_delay);
+            tracing::trace!("`max_delay` for strategy reached");
+            return Some(max_delay);
         }
+        let duration = duration.unwrap_or(Duration::from_millis(u64::MAX));
 
         if let Some(next) = self.current.checked_mul(self.base) {
             self.current = next;
@@ -82,6 +214,70 @@ impl Iterator for ExponentialBackoff {
 
         Some(duration)
     }
+
+    fn nth(&mut self, n: usize) -> Option<Duration> {
+        // once the max delay is reached the strategy stops advancing
+        let frozen = self.max_delay.is_some_and(|max_delay| {
+            self.current
+                .checked_mul(self.factor)
+                .is_none_or(|millis| Duration::from_millis(millis) > max_delay)
+        });
+        if !frozen {
+            let exp = u32::try_from(n).unwrap_or(u32::MAX);
+            self.current = match self.base.checked_pow(exp) {
+                Some(power) => self.current.saturating_mul(power),
+                None if self.current == 0 => 0,
+                None => u64::MAX,
+            };
+        }
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // the strategy never ends
+        (usize::MAX, None)
+    }
+}
+
+/// The running state of a [`ExponentialBackoff`], see [`DurableStrategy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExponentialBackoffState {
+    pub initial: u64,
+    pub current: u64,
+    pub base: u64,
+    pub factor: u64,
+    pub max_delay: Option<Duration>,
+}
+
+impl DurableStrategy for ExponentialBackoff {
+    type State = ExponentialBackoffState;
+
+    fn save_state(&self) -> Self::State {
+        ExponentialBackoffState {
+            initial: self.initial,
+            current: self.current,
+            base: self.base,
+            factor: self.factor,
+            max_delay: self.max_delay,
+        }
+    }
+
+    fn restore_state(state: Self::State) -> Self {
+        let ExponentialBackoffState {
+            initial,
+            current,
+            base,
+            factor,
+            max_delay,
+        } = state;
+        Self {
+            initial,
+            current,
+            base,
+            factor,
+            max_delay,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -94,7 +290,7 @@ mod tests {
 
         assert_eq!(s.next(), Some(Duration::from_millis(10)));
         assert_eq!(s.next(), Some(Duration::from_millis(100)));
-        assert_eq!(s.next(), Some(Duration::from_millis(1000)));
+        assert_eq!(s.next(), Some(Duration::from_secs(1)));
     }
 
     #[test]
@@ -115,6 +311,65 @@ mod tests {
         assert_eq!(s.next(), Some(Duration::from_millis(u64::MAX)));
     }
 
+    #[test]
+    fn fit_matches_endpoints() {
+        let delays: Vec<_> =
+            ExponentialBackoff::fit(Duration::from_millis(100), Duration::from_secs(30), 8)
+                .collect();
+
+        assert_eq!(delays.len(), 8);
+        assert_eq!(delays.first(), Some(&Duration::from_millis(100)));
+        assert!(Duration::from_secs(30).abs_diff(delays[7]) <= Duration::from_millis(1));
+        assert!(delays[6] < Duration::from_secs(15));
+        assert!(delays.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn fit_grows_on_close_endpoints() {
+        let delays: Vec<_> =
+            ExponentialBackoff::fit(Duration::from_millis(100), Duration::from_millis(200), 8)
+                .map(|delay| delay.as_millis())
+                .collect();
+
+        assert_eq!(delays, [100, 110, 121, 134, 148, 164, 181, 199]);
+    }
+
+    #[test]
+    fn fit_clamps_sub_millisecond_start() {
+        let s = ExponentialBackoff::fit(Duration::from_micros(500), Duration::from_millis(200), 4);
+        let delays: Vec<_> = s.map(|delay| delay.as_millis()).collect();
+
+        assert_eq!(delays.first(), Some(&1));
+        assert!(delays.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(delays[3] >= 199);
+    }
+
+    #[test]
+    fn fit_handles_degenerate_endpoints() {
+        let s = ExponentialBackoff::fit(Duration::from_secs(1), Duration::from_millis(10), 3);
+        assert!(s.eq([10, 10, 10].map(Duration::from_millis)));
+
+        let s = ExponentialBackoff::fit(Duration::from_millis(100), Duration::from_secs(1), 1);
+        assert!(s.eq([Duration::from_millis(100)]));
+
+        let s = ExponentialBackoff::fit(Duration::ZERO, Duration::ZERO, 2);
+        assert!(s.eq([Duration::ZERO; 2]));
+    }
+
+    #[test]
+    fn returns_max_delay_when_factor_overflows() {
+        let mut s = ExponentialBackoff::from_millis(2)
+            .factor(u64::MAX)
+            .max_delay(Duration::from_secs(60));
+        assert_eq!(s.next(), Some(Duration::from_secs(60)));
+        assert_eq!(s.next(), Some(Duration::from_secs(60)));
+
+        let mut s = ExponentialBackoff::from_millis(2)
+            .factor(u64::MAX)
+            .max_delay(Duration::MAX);
+        assert_eq!(s.next(), Some(Duration::MAX));
+    }
+
     #[test]
     fn can_use_factor_to_get_seconds() {
         let factor = 1000;
@@ -141,4 +396,76 @@ mod tests {
         assert_eq!(s.next(), Some(Duration::from_millis(10)));
         assert_eq!(s.next(), Some(Duration::from_millis(10)));
     }
+
+    #[test]
+    fn nth_matches_next() {
+        for mut skipped in [
+            ExponentialBackoff::from_millis(2),
+            ExponentialBackoff::from_millis(3).factor(10),
+            ExponentialBackoff::from_millis(2).max_delay_millis(20),
+            ExponentialBackoff::from_millis(u64::MAX / 4),
+        ] {
+            let mut stepped = skipped.clone();
+            for _ in 0..5 {
+                stepped.next();
+            }
+
+            assert_eq!(skipped.nth(5), stepped.next());
+            assert_eq!(skipped.next(), stepped.next());
+        }
+    }
+
+    #[test]
+    fn starting_at_attempt_matches_tail() {
+        let strategy = ExponentialBackoff::from_millis(3)
+            .factor(10)
+            .max_delay_millis(10_000);
+        let resumed = strategy.clone().starting_at_attempt(3).take(5);
+
+        assert!(resumed.eq(strategy.skip(3).take(5)));
+        assert_eq!(
+            ExponentialBackoff::from_millis(2)
+                .starting_at_attempt(u64::MAX)
+                .next(),
+            Some(Duration::from_millis(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn zero_factor_is_clamped_to_one() {
+        let s = ExponentialBackoff::from_millis(10).factor(0).take(3);
+
+        assert!(s.eq([10, 100, 1000].map(Duration::from_millis)));
+    }
+
+    #[test]
+    fn default_sequence() {
+        let s = ExponentialBackoff::default().take(3);
+
+        assert!(s.eq([200, 400, 800].map(Duration::from_millis)));
+    }
+
+    #[test]
+    fn getters_return_construction_parameters() {
+        let mut s = ExponentialBackoff::from_millis(10)
+            .factor(1000)
+            .max_delay_millis(5000);
+        s.nth(3);
+
+        assert_eq!(s.first_delay(), Duration::from_secs(10));
+        assert_eq!(s.base(), 10);
+        assert_eq!(s.delay_factor(), 1000);
+        assert_eq!(s.max_delay_cap(), Some(Duration::from_secs(5)));
+    }
+
+    #[test]
+    fn build_with_total_matches_bounded_sequence() {
+        let (strategy, total) = ExponentialBackoff::from_millis(2)
+            .factor(10)
+            .max_delay(Duration::from_secs(1))
+            .build_with_total(5);
+
+        assert_eq!(strategy.sum::<Duration>(), total);
+        assert_eq!(total, Duration::from_millis(20 + 40 + 80 + 160 + 320));
+    }
 }
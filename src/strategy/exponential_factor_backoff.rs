@@ -1,7 +1,26 @@
-use std::iter::Iterator;
+use std::{error, fmt, iter::Iterator};
 
 use tokio::time::Duration;
 
+use super::DurableStrategy;
+
+/// Error returned by [`ExponentialFactorBackoff::try_new`] for a base factor that can't
+/// produce delays: `NaN`, infinite, zero or negative.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InvalidFactor(pub f64);
+
+impl fmt::Display for InvalidFactor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid base factor `{}`, expected a finite positive number",
+            self.0
+        )
+    }
+}
+
+impl error::Error for InvalidFactor {}
+
 /// A retry strategy driven by exponential factor back-off.
 /// Duration is capped at a maximum value of `u32::MAX millis = 4294967295 ms` ~49 days.
 ///
@@ -31,6 +50,26 @@ impl ExponentialFactorBackoff {
         }
     }
 
+    /// Same as [`ExponentialFactorBackoff::from_millis`], validating the base factor.
+    ///
+    /// A base factor of `1.0` keeps delays constant, and a base factor below `1.0`
+    /// shrinks them on each attempt, both are accepted but suspicious, so they emit a
+    /// `tracing::warn!` under the `tracing` feature.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`InvalidFactor`] if the base factor is `NaN`, infinite, zero or negative.
+    pub fn try_new(initial_delay: u64, base_factor: f64) -> Result<Self, InvalidFactor> {
+        if !base_factor.is_finite() || base_factor <= 0.0 {
+            return Err(InvalidFactor(base_factor));
+        }
+        if base_factor <= 1.0 {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("base factor {base_factor} doesn't grow delays");
+        }
+        Ok(Self::from_millis(initial_delay, base_factor))
+    }
+
     /// Constructs a new exponential factor back-off strategy,
     /// given a base factor. The initial delay is set to `500`.
     /// Starting factor is `1.0` to use `initial_delay` as the base.
@@ -69,6 +108,39 @@ impl ExponentialFactorBackoff {
         self.max_delay = Some(Duration::from_millis(duration));
         self
     }
+
+    /// The first delay, before the maximum delay cap.
+    #[must_use]
+    pub const fn first_delay(&self) -> Duration {
+        Duration::from_millis(self.base)
+    }
+
+    /// The base factor, applied once more to each subsequent delay.
+    #[must_use]
+    pub const fn base_factor(&self) -> f64 {
+        self.base_factor
+    }
+
+    /// The factor applied to the initial delay for the next delay, starting at `1.0`
+    /// and multiplied by the base factor on each attempt.
+    #[must_use]
+    pub const fn current_factor(&self) -> f64 {
+        self.factor
+    }
+
+    /// The maximum delay cap, if any.
+    #[must_use]
+    pub const fn max_delay_cap(&self) -> Option<Duration> {
+        self.max_delay
+    }
+}
+
+/// Defaults to an initial delay of `500ms` with a base factor of `2.0`, without max delay,
+/// so delays are `500ms`, `1s`, `2s`, ...
+impl Default for ExponentialFactorBackoff {
+    fn default() -> Self {
+        Self::from_factor(2.)
+    }
 }
 
 impl Iterator for ExponentialFactorBackoff {
@@ -99,7 +171,7 @@ impl Iterator for ExponentialFactorBackoff {
             && duration > *max_delay
         {
             #[cfg(feature = "tracing")]
-            tracing::warn!("`max_delay` for strategy reached");
+            tracing::trace!("`max_delay` for strategy reached");
             return Some(*max_delay);
         }
 
@@ -108,11 +180,68 @@ impl Iterator for ExponentialFactorBackoff {
 
         Some(duration)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // the strategy never ends
+        (usize::MAX, None)
+    }
+}
+
+/// The running state of a [`ExponentialFactorBackoff`], see [`DurableStrategy`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExponentialFactorBackoffState {
+    pub base: u64,
+    pub factor: f64,
+    pub base_factor: f64,
+    pub max_delay: Option<Duration>,
+}
+
+impl DurableStrategy for ExponentialFactorBackoff {
+    type State = ExponentialFactorBackoffState;
+
+    fn save_state(&self) -> Self::State {
+        ExponentialFactorBackoffState {
+            base: self.base,
+            factor: self.factor,
+            base_factor: self.base_factor,
+            max_delay: self.max_delay,
+        }
+    }
+
+    fn restore_state(state: Self::State) -> Self {
+        let ExponentialFactorBackoffState {
+            base,
+            factor,
+            base_factor,
+            max_delay,
+        } = state;
+        Self {
+            base,
+            factor,
+            base_factor,
+            max_delay,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    #[cfg(feature = "jitter")]
+    use crate::strategy::StrategyExt;
+
+    #[test]
+    #[cfg(feature = "jitter")]
+    fn jitter_stays_within_band_of_computed_delay() {
+        let strategy = ExponentialFactorBackoff::from_millis(10, 1.7).max_delay_millis(10_000);
+        let computed = strategy.clone().take(20);
+        let jittered = strategy.jitter().take(20);
+
+        for (computed, jittered) in computed.zip(jittered) {
+            assert!(jittered >= computed.mul_f64(0.5));
+            assert!(jittered < computed.mul_f64(1.5));
+        }
+    }
 
     #[test]
     fn returns_some_exponential_base_10() {
@@ -120,7 +249,7 @@ mod tests {
 
         assert_eq!(s.next(), Some(Duration::from_millis(10)));
         assert_eq!(s.next(), Some(Duration::from_millis(100)));
-        assert_eq!(s.next(), Some(Duration::from_millis(1000)));
+        assert_eq!(s.next(), Some(Duration::from_secs(1)));
     }
 
     #[test]
@@ -190,8 +319,50 @@ mod tests {
         let mut s = ExponentialFactorBackoff::from_millis(500, 2.);
 
         assert_eq!(s.next(), Some(Duration::from_millis(500)));
-        assert_eq!(s.next(), Some(Duration::from_millis(1000)));
-        assert_eq!(s.next(), Some(Duration::from_millis(2000)));
-        assert_eq!(s.next(), Some(Duration::from_millis(4000)));
+        assert_eq!(s.next(), Some(Duration::from_secs(1)));
+        assert_eq!(s.next(), Some(Duration::from_secs(2)));
+        assert_eq!(s.next(), Some(Duration::from_secs(4)));
+    }
+
+    #[test]
+    fn try_new_accepts_valid_factors() {
+        let mut s = ExponentialFactorBackoff::try_new(10, 2.).unwrap();
+        assert_eq!(s.next(), Some(Duration::from_millis(10)));
+        assert_eq!(s.next(), Some(Duration::from_millis(20)));
+        assert!((s.current_factor() - 4.).abs() < f64::EPSILON);
+
+        let s = ExponentialFactorBackoff::try_new(10, 1.).unwrap().take(3);
+        assert!(s.eq([Duration::from_millis(10); 3]));
+    }
+
+    #[test]
+    fn try_new_rejects_invalid_factors() {
+        assert!(ExponentialFactorBackoff::try_new(10, f64::NAN).is_err());
+        assert!(ExponentialFactorBackoff::try_new(10, f64::INFINITY).is_err());
+        assert_eq!(
+            ExponentialFactorBackoff::try_new(10, 0.).unwrap_err(),
+            InvalidFactor(0.)
+        );
+        assert_eq!(
+            InvalidFactor(-1.).to_string(),
+            "invalid base factor `-1`, expected a finite positive number"
+        );
+    }
+
+    #[test]
+    fn default_sequence() {
+        let s = ExponentialFactorBackoff::default().take(3);
+
+        assert!(s.eq([500, 1000, 2000].map(Duration::from_millis)));
+    }
+
+    #[test]
+    fn getters_return_construction_parameters() {
+        let mut s = ExponentialFactorBackoff::from_millis(10, 1.5).max_delay_millis(50);
+        s.nth(3);
+
+        assert_eq!(s.first_delay(), Duration::from_millis(10));
+        assert!((s.base_factor() - 1.5).abs() < f64::EPSILON);
+        assert_eq!(s.max_delay_cap(), Some(Duration::from_millis(50)));
     }
 }
@@ -0,0 +1,237 @@
+use std::iter::Iterator;
+
+use tokio::time::Duration;
+
+use super::DurableStrategy;
+
+/// A retry strategy driven by exponential back-off, using integer-only arithmetic.
+///
+/// Each delay is the previous one multiplied by `numerator / denominator`, so delay
+/// sequences are bit-for-bit reproducible across platforms, unlike the float factor
+/// of `ExponentialFactorBackoff`. Each step is truncated towards zero, and the delay
+/// saturates at `u64::MAX` milliseconds.
+///
+/// ```
+/// use std::time::Duration;
+/// use tokio_retry2::strategy::ExponentialRationalBackoff;
+///
+/// // grow by 1.5x on each attempt
+/// let mut strategy = ExponentialRationalBackoff::from_millis(100, 3, 2);
+///
+/// assert_eq!(strategy.next(), Some(Duration::from_millis(100)));
+/// assert_eq!(strategy.next(), Some(Duration::from_millis(150)));
+/// assert_eq!(strategy.next(), Some(Duration::from_millis(225)));
+/// ```
+#[derive(Debug, Clone)]
+pub struct ExponentialRationalBackoff {
+    initial: u64,
+    current: u64,
+    numerator: u64,
+    denominator: u64,
+    max_delay: Option<Duration>,
+}
+
+impl ExponentialRationalBackoff {
+    /// Constructs a new integer-only exponential back-off strategy,
+    /// given an initial delay in milliseconds and a growth of `numerator / denominator`
+    /// per attempt.
+    ///
+    /// A `denominator` of `0` makes the delay saturate after the first attempt.
+    #[must_use]
+    pub const fn from_millis(initial_delay: u64, numerator: u64, denominator: u64) -> Self {
+        Self {
+            initial: initial_delay,
+            current: initial_delay,
+            numerator,
+            denominator,
+            max_delay: None,
+        }
+    }
+
+    /// Apply a maximum delay. No single retry delay will be longer than this `Duration`.
+    #[must_use]
+    pub const fn max_delay(mut self, duration: Duration) -> Self {
+        self.max_delay = Some(duration);
+        self
+    }
+
+    /// Apply a maximum delay. No single retry delay will be longer than this `Duration::from_millis`.
+    #[must_use]
+    pub const fn max_delay_millis(mut self, duration: u64) -> Self {
+        self.max_delay = Some(Duration::from_millis(duration));
+        self
+    }
+
+    /// The first delay, before the maximum delay cap.
+    #[must_use]
+    pub const fn first_delay(&self) -> Duration {
+        Duration::from_millis(self.initial)
+    }
+
+    /// The numerator of the growth applied on each attempt.
+    #[must_use]
+    pub const fn numerator(&self) -> u64 {
+        self.numerator
+    }
+
+    /// The denominator of the growth applied on each attempt.
+    #[must_use]
+    pub const fn denominator(&self) -> u64 {
+        self.denominator
+    }
+
+    /// The maximum delay cap, if any.
+    #[must_use]
+    pub const fn max_delay_cap(&self) -> Option<Duration> {
+        self.max_delay
+    }
+}
+
+/// Defaults to an initial delay of `100ms` doubling on each attempt, without max delay,
+/// so delays are `100ms`, `200ms`, `400ms`, ...
+impl Default for ExponentialRationalBackoff {
+    fn default() -> Self {
+        Self::from_millis(100, 2, 1)
+    }
+}
+
+impl Iterator for ExponentialRationalBackoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        let duration = Duration::from_millis(self.current);
+
+        // check if we reached max delay
+        if let Some(ref max_delay) = self.max_delay
+            && duration > *max_delay
+        {
+            #[cfg(feature = "tracing")]
+            tracing::trace!("`max_delay` for strategy reached");
+            return Some(*max_delay);
+        }
+
+        self.current = u128::from(self.current)
+            .checked_mul(u128::from(self.numerator))
+            .and_then(|next| next.checked_div(u128::from(self.denominator)))
+            .map_or(u64::MAX, |next| u64::try_from(next).unwrap_or(u64::MAX));
+
+        Some(duration)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // the strategy never ends
+        (usize::MAX, None)
+    }
+}
+
+/// The running state of a [`ExponentialRationalBackoff`], see [`DurableStrategy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExponentialRationalBackoffState {
+    pub initial: u64,
+    pub current: u64,
+    pub numerator: u64,
+    pub denominator: u64,
+    pub max_delay: Option<Duration>,
+}
+
+impl DurableStrategy for ExponentialRationalBackoff {
+    type State = ExponentialRationalBackoffState;
+
+    fn save_state(&self) -> Self::State {
+        ExponentialRationalBackoffState {
+            initial: self.initial,
+            current: self.current,
+            numerator: self.numerator,
+            denominator: self.denominator,
+            max_delay: self.max_delay,
+        }
+    }
+
+    fn restore_state(state: Self::State) -> Self {
+        let ExponentialRationalBackoffState {
+            initial,
+            current,
+            numerator,
+            denominator,
+            max_delay,
+        } = state;
+        Self {
+            initial,
+            current,
+            numerator,
+            denominator,
+            max_delay,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn returns_some_exponential_ratio_2() {
+        let mut s = ExponentialRationalBackoff::from_millis(10, 2, 1);
+
+        assert_eq!(s.next(), Some(Duration::from_millis(10)));
+        assert_eq!(s.next(), Some(Duration::from_millis(20)));
+        assert_eq!(s.next(), Some(Duration::from_millis(40)));
+    }
+
+    #[test]
+    fn sequences_are_identical() {
+        let a: Vec<_> = ExponentialRationalBackoff::from_millis(7, 5, 3)
+            .take(20)
+            .collect();
+        let b: Vec<_> = ExponentialRationalBackoff::from_millis(7, 5, 3)
+            .take(20)
+            .collect();
+
+        assert_eq!(a, b);
+        assert_eq!(a[..4], [7, 11, 18, 30].map(Duration::from_millis));
+    }
+
+    #[test]
+    fn saturates_at_maximum_value() {
+        let mut s = ExponentialRationalBackoff::from_millis(u64::MAX - 1, 3, 2);
+
+        assert_eq!(s.next(), Some(Duration::from_millis(u64::MAX - 1)));
+        assert_eq!(s.next(), Some(Duration::from_millis(u64::MAX)));
+        assert_eq!(s.next(), Some(Duration::from_millis(u64::MAX)));
+    }
+
+    #[test]
+    fn saturates_on_zero_denominator() {
+        let mut s = ExponentialRationalBackoff::from_millis(10, 3, 0);
+
+        assert_eq!(s.next(), Some(Duration::from_millis(10)));
+        assert_eq!(s.next(), Some(Duration::from_millis(u64::MAX)));
+    }
+
+    #[test]
+    fn stops_increasing_at_max_delay() {
+        let mut s = ExponentialRationalBackoff::from_millis(2, 2, 1).max_delay_millis(4);
+
+        assert_eq!(s.next(), Some(Duration::from_millis(2)));
+        assert_eq!(s.next(), Some(Duration::from_millis(4)));
+        assert_eq!(s.next(), Some(Duration::from_millis(4)));
+    }
+
+    #[test]
+    fn default_sequence() {
+        let s = ExponentialRationalBackoff::default().take(3);
+
+        assert!(s.eq([100, 200, 400].map(Duration::from_millis)));
+    }
+
+    #[test]
+    fn getters_return_construction_parameters() {
+        let mut s = ExponentialRationalBackoff::from_millis(100, 3, 2).max_delay_millis(500);
+        s.nth(3);
+
+        assert_eq!(s.first_delay(), Duration::from_millis(100));
+        assert_eq!(s.numerator(), 3);
+        assert_eq!(s.denominator(), 2);
+        assert_eq!(s.max_delay_cap(), Some(Duration::from_millis(500)));
+    }
+}
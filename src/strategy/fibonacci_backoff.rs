@@ -2,6 +2,8 @@ use std::iter::Iterator;
 
 use tokio::time::Duration;
 
+use super::{Bounded, DurableStrategy};
+
 /// A retry strategy driven by the fibonacci series.
 ///
 /// Each retry uses a delay which is the sum of the two previous delays.
@@ -14,6 +16,7 @@ use tokio::time::Duration;
 /// for more details.
 #[derive(Debug, Clone)]
 pub struct FibonacciBackoff {
+    base: u64,
     current: u64,
     next: u64,
     factor: u64,
@@ -26,6 +29,7 @@ impl FibonacciBackoff {
     #[must_use]
     pub const fn from_millis(millis: u64) -> Self {
         Self {
+            base: millis,
             current: millis,
             next: millis,
             factor: 1u64,
@@ -37,10 +41,11 @@ impl FibonacciBackoff {
     ///
     /// For example, using a factor of `1000` will make each delay in units of seconds.
     ///
-    /// Default factor is `1`.
+    /// Default factor is `1`. A factor of `0` would turn every delay into a tight retry
+    /// loop, so it is clamped to `1`.
     #[must_use]
     pub const fn factor(mut self, factor: u64) -> Self {
-        self.factor = factor;
+        self.factor = if factor == 0 { 1 } else { factor };
         self
     }
 
@@ -57,37 +62,148 @@ impl FibonacciBackoff {
         self.max_delay = Some(Duration::from_millis(duration));
         self
     }
+
+    /// Advances the strategy as if `n` delays were already yielded, so the first delay is
+    /// the one of attempt `n + 1`. Useful to resume a persisted retry state.
+    #[must_use]
+    pub fn starting_at_attempt(mut self, n: u64) -> Self {
+        self.current = self.base;
+        self.next = self.base;
+        for _ in 0..n {
+            let (current, next) = (self.current, self.next);
+            self.advance();
+            // the series is stuck, at zero or saturated, no need to go further
+            if (current, next) == (self.current, self.next) {
+                break;
+            }
+        }
+        self
+    }
+
+    const fn advance(&mut self) {
+        if let Some(next_next) = self.current.checked_add(self.next) {
+            self.current = self.next;
+            self.next = next_next;
+        } else {
+            self.current = self.next;
+            self.next = u64::MAX;
+        }
+    }
+
+    /// Limits the strategy to `max_retries` delays, same as `.take(max_retries)`,
+    /// but with a nameable type.
+    ///
+    /// ```
+    /// use tokio_retry2::strategy::{Bounded, FibonacciBackoff};
+    ///
+    /// fn strategy() -> Bounded<FibonacciBackoff> {
+    ///     FibonacciBackoff::from_millis(10).with_max_retries(3)
+    /// }
+    ///
+    /// assert_eq!(strategy().count(), 3);
+    /// ```
+    #[must_use]
+    pub const fn with_max_retries(self, max_retries: usize) -> Bounded<Self> {
+        Bounded::new(self, max_retries)
+    }
+
+    /// The first delay, with the factor applied, before the maximum delay cap.
+    #[must_use]
+    pub const fn first_delay(&self) -> Duration {
+        Duration::from_millis(self.base.saturating_mul(self.factor))
+    }
+
+    /// The multiplicative factor applied to each delay.
+    #[must_use]
+    pub const fn delay_factor(&self) -> u64 {
+        self.factor
+    }
+
+    /// The maximum delay cap, if any.
+    #[must_use]
+    pub const fn max_delay_cap(&self) -> Option<Duration> {
+        self.max_delay
+    }
+}
+
+/// Defaults to `100ms` with a factor of `1`, without max delay,
+/// so delays are `100ms`, `100ms`, `200ms`, `300ms`, ...
+impl Default for FibonacciBackoff {
+    fn default() -> Self {
+        Self::from_millis(100)
+    }
 }
 
 impl Iterator for FibonacciBackoff {
     type Item = Duration;
 
     fn next(&mut self) -> Option<Duration> {
-        // set delay duration by applying factor
+        // set delay duration by applying factor, `None` on overflow
         let duration = self
             .current
             .checked_mul(self.factor)
-            .map_or_else(|| Duration::from_millis(u64::MAX), Duration::from_millis);
+            .map(Duration::from_millis);
 
-        // check if we reached max delay
-        if let Some(ref max_delay) = self.max_delay
-            && duration > *max_delay
+        // check if we reached max delay, an overflowing delay always exceeds it
+        if let Some(max_delay) = self.max_delay
+            && duration.is_none_or(|duration| duration > max_delay)
         {
             #[cfg(feature = "tracing")]
-            tracing::warn!("`max_delay` for strategy reached");
-            return Some(*max_delay);
+            tracing::trace!("`max_delay` for strategy reached");
+            return Some(max_delay);
         }
+        let duration = duration.unwrap_or(Duration::from_millis(u64::MAX));
 
-        if let Some(next_next) = self.current.checked_add(self.next) {
-            self.current = self.next;
-            self.next = next_next;
-        } else {
-            self.current = self.next;
-            self.next = u64::MAX;
-        }
+        self.advance();
 
         Some(duration)
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // the strategy never ends
+        (usize::MAX, None)
+    }
+}
+
+/// The running state of a [`FibonacciBackoff`], see [`DurableStrategy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FibonacciBackoffState {
+    pub base: u64,
+    pub current: u64,
+    pub next: u64,
+    pub factor: u64,
+    pub max_delay: Option<Duration>,
+}
+
+impl DurableStrategy for FibonacciBackoff {
+    type State = FibonacciBackoffState;
+
+    fn save_state(&self) -> Self::State {
+        FibonacciBackoffState {
+            base: self.base,
+            current: self.current,
+            next: self.next,
+            factor: self.factor,
+            max_delay: self.max_delay,
+        }
+    }
+
+    fn restore_state(state: Self::State) -> Self {
+        let FibonacciBackoffState {
+            base,
+            current,
+            next,
+            factor,
+            max_delay,
+        } = state;
+        Self {
+            base,
+            current,
+            next,
+            factor,
+            max_delay,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -111,6 +227,35 @@ mod tests {
         assert_eq!(iter.next(), Some(Duration::from_millis(u64::MAX)));
     }
 
+    #[test]
+    fn returns_max_delay_when_factor_overflows() {
+        let mut iter = FibonacciBackoff::from_millis(u64::MAX / 2)
+            .factor(3)
+            .max_delay(Duration::from_secs(60));
+        assert_eq!(iter.next(), Some(Duration::from_secs(60)));
+        assert_eq!(iter.next(), Some(Duration::from_secs(60)));
+
+        let mut iter = FibonacciBackoff::from_millis(u64::MAX / 2)
+            .factor(3)
+            .max_delay(Duration::MAX);
+        assert_eq!(iter.next(), Some(Duration::MAX));
+    }
+
+    #[test]
+    fn restored_state_continues_the_sequence() {
+        let mut iter = FibonacciBackoff::from_millis(10)
+            .factor(2)
+            .max_delay_millis(1_000);
+        iter.by_ref().take(4).for_each(drop);
+
+        let state = iter.save_state();
+        assert_eq!(state.current, 50);
+        assert_eq!(state.next, 80);
+
+        let restored = FibonacciBackoff::restore_state(state);
+        assert!(restored.take(10).eq(iter.take(10)));
+    }
+
     #[test]
     fn stops_increasing_at_max_delay() {
         let mut iter = FibonacciBackoff::from_millis(10).max_delay(Duration::from_millis(50));
@@ -139,4 +284,63 @@ mod tests {
         assert_eq!(s.next(), Some(Duration::from_secs(1)));
         assert_eq!(s.next(), Some(Duration::from_secs(2)));
     }
+
+    #[test]
+    fn starting_at_attempt_matches_tail() {
+        let strategy = FibonacciBackoff::from_millis(10).max_delay_millis(1_000);
+        let resumed = strategy.clone().starting_at_attempt(3).take(10);
+
+        assert!(resumed.eq(strategy.skip(3).take(10)));
+        assert_eq!(
+            FibonacciBackoff::from_millis(0)
+                .starting_at_attempt(u64::MAX)
+                .next(),
+            Some(Duration::ZERO)
+        );
+        assert_eq!(
+            FibonacciBackoff::from_millis(1)
+                .starting_at_attempt(u64::MAX)
+                .next(),
+            Some(Duration::from_millis(u64::MAX))
+        );
+    }
+
+    #[test]
+    fn zero_factor_is_clamped_to_one() {
+        let s = FibonacciBackoff::from_millis(10).factor(0).take(3);
+
+        assert!(s.eq([10, 10, 20].map(Duration::from_millis)));
+    }
+
+    #[test]
+    fn default_sequence() {
+        let s = FibonacciBackoff::default().take(4);
+
+        assert!(s.eq([100, 100, 200, 300].map(Duration::from_millis)));
+    }
+
+    #[test]
+    fn getters_return_construction_parameters() {
+        let mut s = FibonacciBackoff::from_millis(10).factor(1000);
+        s.nth(3);
+
+        assert_eq!(s.first_delay(), Duration::from_secs(10));
+        assert_eq!(s.delay_factor(), 1000);
+        assert_eq!(s.max_delay_cap(), None);
+    }
+
+    #[test]
+    fn with_max_retries_yields_exactly_n_delays() {
+        let s = FibonacciBackoff::from_millis(10).with_max_retries(4);
+
+        assert_eq!(
+            s.collect::<Vec<_>>(),
+            vec![
+                Duration::from_millis(10),
+                Duration::from_millis(10),
+                Duration::from_millis(20),
+                Duration::from_millis(30)
+            ]
+        );
+    }
 }
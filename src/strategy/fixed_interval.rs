@@ -1,7 +1,16 @@
 use std::iter::Iterator;
+#[cfg(feature = "jitter")]
+use std::iter::{Map, Take};
 
 use tokio::time::Duration;
 
+use super::DurableStrategy;
+
+/// A jittered and bounded fixed interval strategy,
+/// created by [`FixedInterval::jittered_take`] function.
+#[cfg(feature = "jitter")]
+pub type JitteredFixedInterval = Take<Map<FixedInterval, fn(Duration) -> Duration>>;
+
 /// A retry strategy driven by a fixed interval.
 #[derive(Debug, Clone)]
 pub struct FixedInterval {
@@ -18,11 +27,68 @@ impl FixedInterval {
         }
     }
 
+    /// Constructs a new fixed interval strategy,
+    /// given a duration in seconds.
+    #[must_use]
+    pub const fn from_secs(secs: u64) -> Self {
+        Self {
+            duration: Duration::from_secs(secs),
+        }
+    }
+
     /// Constructs a new fixed interval strategy.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tokio_retry2::strategy::FixedInterval;
+    ///
+    /// let mut strategy = FixedInterval::new(Duration::from_secs(2));
+    ///
+    /// assert_eq!(strategy.next(), Some(Duration::from_secs(2)));
+    /// assert_eq!(strategy.next(), Some(Duration::from_secs(2)));
+    /// ```
     #[must_use]
     pub const fn new(duration: Duration) -> Self {
         Self { duration }
     }
+
+    /// The fixed interval between retries.
+    #[must_use]
+    pub const fn interval(&self) -> Duration {
+        self.duration
+    }
+
+    /// Constructs a new fixed interval strategy, picking the interval at random in
+    /// `[min, max]` once, e.g. to spread a cohort of clients across a window while each
+    /// client keeps retrying at a steady interval. Inverted bounds are swapped.
+    #[cfg(feature = "jitter")]
+    #[must_use]
+    pub fn random_in(min: Duration, max: Duration) -> Self {
+        let (min, max) = if min > max { (max, min) } else { (min, max) };
+        Self::new(rand::random_range(min..=max))
+    }
+
+    /// Applies the default [`jitter`](crate::strategy::jitter) and limits the strategy
+    /// to `n` retries, same as `.map(jitter).take(n)`.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tokio_retry2::strategy::{FixedInterval, JitteredFixedInterval};
+    ///
+    /// let strategy: JitteredFixedInterval = FixedInterval::from_millis(100).jittered_take(3);
+    /// assert_eq!(strategy.count(), 3);
+    /// ```
+    #[cfg(feature = "jitter")]
+    pub fn jittered_take(self, n: usize) -> JitteredFixedInterval {
+        self.map(super::jitter as fn(Duration) -> Duration).take(n)
+    }
+}
+
+/// Defaults to a fixed interval of `100ms`.
+impl Default for FixedInterval {
+    fn default() -> Self {
+        Self::from_millis(100)
+    }
 }
 
 impl Iterator for FixedInterval {
@@ -31,6 +97,36 @@ impl Iterator for FixedInterval {
     fn next(&mut self) -> Option<Duration> {
         Some(self.duration)
     }
+
+    fn nth(&mut self, _n: usize) -> Option<Duration> {
+        Some(self.duration)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // the strategy never ends
+        (usize::MAX, None)
+    }
+}
+
+/// The running state of a [`FixedInterval`], see [`DurableStrategy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FixedIntervalState {
+    pub duration: Duration,
+}
+
+impl DurableStrategy for FixedInterval {
+    type State = FixedIntervalState;
+
+    fn save_state(&self) -> Self::State {
+        FixedIntervalState {
+            duration: self.duration,
+        }
+    }
+
+    fn restore_state(state: Self::State) -> Self {
+        let FixedIntervalState { duration } = state;
+        Self { duration }
+    }
 }
 
 #[cfg(test)]
@@ -45,4 +141,57 @@ mod tests {
         assert_eq!(s.next(), Some(Duration::from_millis(123)));
         assert_eq!(s.next(), Some(Duration::from_millis(123)));
     }
+
+    #[test]
+    fn nth_matches_next() {
+        let mut s = FixedInterval::from_millis(123);
+        assert_eq!(s.nth(5), Some(Duration::from_millis(123)));
+    }
+
+    #[test]
+    fn default_sequence() {
+        let s = FixedInterval::default().take(3);
+
+        assert!(s.eq([Duration::from_millis(100); 3]));
+    }
+
+    #[test]
+    fn interval_returns_construction_parameter() {
+        let mut s = FixedInterval::from_millis(250);
+        s.next();
+
+        assert_eq!(s.interval(), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn from_secs_matches_new() {
+        let s = FixedInterval::from_secs(2).take(2);
+
+        assert!(s.eq(FixedInterval::new(Duration::from_secs(2)).take(2)));
+    }
+
+    #[test]
+    #[cfg(feature = "jitter")]
+    fn random_in_picks_one_interval() {
+        let (min, max) = (Duration::from_millis(100), Duration::from_millis(200));
+        let delays: Vec<_> = FixedInterval::random_in(min, max).take(10).collect();
+
+        assert!(delays.iter().all(|delay| *delay == delays[0]));
+        assert!((min..=max).contains(&delays[0]));
+
+        let s = FixedInterval::random_in(max, min);
+        assert!((min..=max).contains(&s.interval()));
+    }
+
+    #[test]
+    #[cfg(feature = "jitter")]
+    fn jittered_take_yields_n_jittered_values() {
+        let delays: Vec<_> = FixedInterval::from_millis(100).jittered_take(5).collect();
+
+        assert_eq!(delays.len(), 5);
+        for delay in delays {
+            assert!(delay >= Duration::from_millis(50));
+            assert!(delay <= Duration::from_millis(150));
+        }
+    }
 }
@@ -1,26 +1,266 @@
-use rand::distr::uniform::SampleRange;
-use tokio::time::Duration;
+use std::{
+    cell::RefCell,
+    error, fmt,
+    hash::{DefaultHasher, Hash, Hasher},
+};
+
+use rand::{Rng, SeedableRng, distr::uniform::SampleRange, rngs::SmallRng};
+use tokio::time::{Duration, Instant};
+
+use super::strategy_ext::scale;
+
+thread_local! {
+    static FAST_RNG: RefCell<SmallRng> = RefCell::new(SmallRng::from_rng(&mut rand::rng()));
+}
 
 /// defines `jitter` based on specific duration
+///
+/// Samples from `rand`'s thread-local cryptographically secure generator, so there is no
+/// contention between threads. See [`jitter_fast`] for hot paths.
 #[must_use]
 pub fn jitter(duration: Duration) -> Duration {
     duration.mul_f64(rand::random::<f64>() + 0.5)
 }
 
+/// Same as [`jitter`], but samples from a cached thread-local `SmallRng`.
+///
+/// `SmallRng` is cheaper to sample than the default generator, but not cryptographically
+/// secure and its sequence is predictable, which is fine to spread retries.
+///
+/// The jittered duration saturates at `Duration::MAX`.
+#[must_use]
+pub fn jitter_fast(duration: Duration) -> Duration {
+    let factor = FAST_RNG.with(|rng| rng.borrow_mut().random::<f64>());
+    scale(duration, factor + 0.5)
+}
+
+/// Same as [`jitter`], but seeds the generator with the hash of `seed`, e.g. the request
+/// or the error, so the same input always produces the same delay.
+///
+/// Useful to reproduce production retry timings in tests. The hash, and so the delay, is
+/// only stable for a given build of the crate and its dependencies. The jittered duration
+/// saturates at `Duration::MAX`.
+#[must_use]
+pub fn jitter_from_hash(duration: Duration, seed: impl Hash) -> Duration {
+    let mut hasher = DefaultHasher::new();
+    seed.hash(&mut hasher);
+    let factor = SmallRng::seed_from_u64(hasher.finish()).random::<f64>();
+    scale(duration, factor + 0.5)
+}
+
+/// defines `jitter` based on specific duration, never going below `floor`.
+///
+/// Useful to avoid zero-duration sleeps and hot retry loops:
+/// `strategy.map(|d| jitter_floored(d, Duration::from_millis(5)))`.
+#[must_use]
+pub fn jitter_floored(duration: Duration, floor: Duration) -> Duration {
+    jitter(duration).max(floor)
+}
+
+/// Wraps a jitter function, e.g. [`jitter`], limiting the deviation of the jittered
+/// duration from the input to `max_abs`, whatever the input.
+///
+/// Useful with large delays, where a relative jitter adds a huge absolute spread:
+/// `strategy.map(jitter_capped(jitter, Duration::from_secs(1)))`.
+pub fn jitter_capped<F: Fn(Duration) -> Duration>(
+    base_fn: F,
+    max_abs: Duration,
+) -> impl Fn(Duration) -> Duration {
+    move |x| base_fn(x).clamp(x.saturating_sub(max_abs), x.saturating_add(max_abs))
+}
+
+/// Wraps a jitter function, e.g. [`jitter`], only applying it above `threshold`.
+///
+/// Small delays stay precise and only large ones are spread:
+/// `strategy.map(jitter_above(Duration::from_secs(1), jitter))`.
+pub fn jitter_above<F: Fn(Duration) -> Duration>(
+    threshold: Duration,
+    inner: F,
+) -> impl Fn(Duration) -> Duration {
+    move |x| if x > threshold { inner(x) } else { x }
+}
+
+/// Error returned by [`try_jitter_with_bounds`] for bounds that are `NaN`, infinite,
+/// negative or inverted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct InvalidJitterBounds {
+    pub min: f64,
+    pub max: f64,
+}
+
+impl fmt::Display for InvalidJitterBounds {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "invalid jitter bounds `{}..{}`, expected finite non-negative numbers with min <= max",
+            self.min, self.max
+        )
+    }
+}
+
+impl error::Error for InvalidJitterBounds {}
+
+fn valid_bounds(min: f64, max: f64) -> bool {
+    min.is_finite() && max.is_finite() && min >= 0.0 && min <= max
+}
+
 /// defines `jitter` based on explicit bounds
+///
+/// Inverted bounds are swapped, and `NaN`, infinite or negative bounds leave the duration
+/// unchanged. See [`try_jitter_with_bounds`] to reject them instead.
 pub fn jitter_with_bounds(min: f64, max: f64) -> impl Fn(Duration) -> Duration {
+    let (min, max) = if min > max { (max, min) } else { (min, max) };
+    let (min, max) = if valid_bounds(min, max) {
+        (min, max)
+    } else {
+        (1.0, 1.0)
+    };
     move |x| x.mul_f64(rand::random::<f64>().mul_add(max - min, min))
 }
 
+/// Same as [`jitter_with_bounds`], rejecting invalid bounds.
+///
+/// # Errors
+///
+/// Returns [`InvalidJitterBounds`] if a bound is `NaN`, infinite or negative, or if
+/// `min` is greater than `max`.
+pub fn try_jitter_with_bounds(
+    min: f64,
+    max: f64,
+) -> Result<impl Fn(Duration) -> Duration, InvalidJitterBounds> {
+    if !valid_bounds(min, max) {
+        return Err(InvalidJitterBounds { min, max });
+    }
+    Ok(jitter_with_bounds(min, max))
+}
+
+/// defines downward-only `jitter`, scaling the duration by a factor in `[min_factor, 1.0]`,
+/// so a jittered delay never exceeds the computed one.
+///
+/// `min_factor` is clamped to `[0.0, 1.0]`, and `NaN` disables the jitter.
+pub fn jitter_down(min_factor: f64) -> impl Fn(Duration) -> Duration {
+    let min = if min_factor.is_nan() {
+        1.0
+    } else {
+        min_factor.clamp(0.0, 1.0)
+    };
+    jitter_with_bounds(min, 1.0)
+}
+
 /// defines `jitter` based on range
+///
+/// Empty or inverted ranges, like `5..5` or `5..3`, don't panic and leave the duration unchanged.
 pub fn jitter_range<R: SampleRange<u32>>(r: R) -> impl Fn(Duration) -> Duration {
-    let range = rand::random_range(r);
+    let range = if r.is_empty() {
+        1
+    } else {
+        rand::random_range(r)
+    };
     move |x| x * range
 }
 
+/// Wraps a strategy, applying `jitter` capped to `fraction` of the remaining time budget.
+///
+/// Backoff and jitter never overrun the budget, e.g. the timeout of
+/// [`Retry::spawn_with_overall_timeout`](crate::Retry::spawn_with_overall_timeout).
+/// The budget starts at construction, and the strategy stops once it is spent.
+/// `fraction` is clamped to `[0.0, 1.0]`, `NaN` meaning `1.0`.
+pub fn jitter_within_budget<I: IntoIterator<Item = Duration>>(
+    strategy: I,
+    budget: Duration,
+    fraction: f64,
+) -> JitterWithinBudget<I::IntoIter> {
+    JitterWithinBudget {
+        iter: strategy.into_iter(),
+        deadline: Instant::now().checked_add(budget),
+        fraction: if fraction.is_nan() {
+            1.0
+        } else {
+            fraction.clamp(0.0, 1.0)
+        },
+    }
+}
+
+/// A strategy wrapper jittering delays within a time budget,
+/// created by [`jitter_within_budget`] function.
+#[derive(Debug, Clone)]
+pub struct JitterWithinBudget<I> {
+    iter: I,
+    deadline: Option<Instant>,
+    fraction: f64,
+}
+
+impl<I: Iterator<Item = Duration>> Iterator for JitterWithinBudget<I> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let delay = jitter(self.iter.next()?);
+        // an unrepresentable deadline is an unbounded budget
+        let Some(deadline) = self.deadline else {
+            return Some(delay);
+        };
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("jitter budget spent, cancelling retry");
+            return None;
+        }
+        Some(delay.min(remaining.mul_f64(self.fraction)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        if self.deadline.is_some() {
+            (0, upper)
+        } else {
+            (lower, upper)
+        }
+    }
+}
+
+/// Wraps a strategy, applying `jitter` only to the growth between consecutive delays.
+///
+/// Each delay is the previous raw delay plus the jittered increment, and never lower
+/// than the previously yielded delay, so the sequence stays non-decreasing.
+pub fn jitter_delta<I: IntoIterator<Item = Duration>>(strategy: I) -> JitterDelta<I::IntoIter> {
+    JitterDelta {
+        iter: strategy.into_iter(),
+        previous: Duration::ZERO,
+        yielded: Duration::ZERO,
+    }
+}
+
+/// A strategy wrapper jittering the growth between delays,
+/// created by [`jitter_delta`] function.
+#[derive(Debug, Clone)]
+pub struct JitterDelta<I> {
+    iter: I,
+    previous: Duration,
+    yielded: Duration,
+}
+
+impl<I: Iterator<Item = Duration>> Iterator for JitterDelta<I> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let delay = self.iter.next()?;
+        let delta = jitter(delay.saturating_sub(self.previous));
+        let jittered = self.previous.saturating_add(delta).max(self.yielded);
+
+        self.previous = delay;
+        self.yielded = jittered;
+        Some(jittered)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::strategy::{ExponentialFactorBackoff, FixedInterval};
 
     #[test]
     fn test_jitter() {
@@ -30,6 +270,102 @@ mod tests {
         assert!(jitter.as_millis() != 100);
     }
 
+    #[test]
+    fn test_jitter_fast() {
+        let jittered: Vec<_> = (0..100)
+            .map(|_| jitter_fast(Duration::from_millis(100)))
+            .collect();
+
+        assert!(jittered.iter().all(|jitter| jitter.as_millis() >= 50));
+        assert!(jittered.iter().all(|jitter| jitter.as_millis() <= 150));
+        assert!(jittered.iter().any(|jitter| *jitter != jittered[0]));
+    }
+
+    #[test]
+    fn jitter_fast_saturates() {
+        for _ in 0..100 {
+            assert!(jitter_fast(Duration::MAX) >= Duration::MAX / 2);
+        }
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_jitter_within_budget() {
+        let budget = Duration::from_secs(1);
+        let start = Instant::now();
+        let s = jitter_within_budget(FixedInterval::from_millis(400), budget, 0.5);
+
+        for delay in s {
+            let remaining = budget.saturating_sub(start.elapsed());
+            assert!(delay <= remaining.mul_f64(0.5));
+            tokio::time::advance(delay.max(Duration::from_millis(1))).await;
+        }
+    }
+
+    #[test]
+    fn test_jitter_floored() {
+        let floor = Duration::from_millis(5);
+        for _ in 0..100 {
+            let jitter = jitter_floored(Duration::from_millis(10), floor);
+            assert!(jitter >= floor);
+            assert!(jitter.as_millis() <= 15);
+        }
+        assert_eq!(jitter_floored(Duration::ZERO, floor), floor);
+    }
+
+    #[test]
+    fn test_jitter_capped() {
+        let capped = jitter_capped(jitter, Duration::from_secs(1));
+        for _ in 0..100 {
+            let jitter = capped(Duration::from_secs(10));
+            assert!(jitter >= Duration::from_secs(9));
+            assert!(jitter <= Duration::from_secs(11));
+        }
+        assert_eq!(
+            jitter_capped(|_| Duration::MAX, Duration::from_secs(1))(Duration::MAX),
+            Duration::MAX
+        );
+    }
+
+    #[test]
+    fn test_jitter_above() {
+        let jitter = jitter_above(Duration::from_millis(100), jitter);
+        assert_eq!(jitter(Duration::from_millis(10)), Duration::from_millis(10));
+        assert_eq!(
+            jitter(Duration::from_millis(100)),
+            Duration::from_millis(100)
+        );
+
+        let jittered: Vec<_> = (0..100).map(|_| jitter(Duration::from_secs(1))).collect();
+        assert!(jittered.iter().all(|jitter| jitter.as_millis() >= 500));
+        assert!(jittered.iter().all(|jitter| jitter.as_millis() <= 1500));
+        assert!(
+            jittered
+                .iter()
+                .any(|jitter| *jitter != Duration::from_secs(1))
+        );
+    }
+
+    #[test]
+    fn jitter_from_hash_is_deterministic() {
+        let input = Duration::from_millis(100);
+        let jittered = jitter_from_hash(input, ("GET /users", 42));
+
+        assert_eq!(jitter_from_hash(input, ("GET /users", 42)), jittered);
+        assert!(jittered >= Duration::from_millis(50));
+        assert!(jittered < Duration::from_millis(150));
+        assert!(
+            (0..10).any(|id| jitter_from_hash(input, ("GET /users", id)) != jittered),
+            "different inputs should spread the delays"
+        );
+    }
+
+    #[test]
+    fn jitter_from_hash_saturates() {
+        for seed in 0..100 {
+            assert!(jitter_from_hash(Duration::MAX, seed) >= Duration::MAX / 2);
+        }
+    }
+
     #[test]
     fn test_jitter_with_bounds() {
         let jitter = jitter_with_bounds(0.01, 0.1)(Duration::from_millis(100));
@@ -48,10 +384,84 @@ mod tests {
         assert!(jitter.as_millis() != 100);
     }
 
+    #[test]
+    fn jitter_with_bounds_swaps_inverted_bounds() {
+        let jitter = jitter_with_bounds(0.5, 0.4);
+        for _ in 0..100 {
+            let jittered = jitter(Duration::from_millis(100));
+            assert!(jittered >= Duration::from_millis(40));
+            assert!(jittered <= Duration::from_millis(50));
+        }
+    }
+
+    #[test]
+    fn jitter_with_bounds_ignores_invalid_bounds() {
+        let input = Duration::from_millis(100);
+
+        assert_eq!(jitter_with_bounds(f64::NAN, 0.5)(input), input);
+        assert_eq!(jitter_with_bounds(0.5, f64::INFINITY)(input), input);
+        assert_eq!(jitter_with_bounds(-0.5, 0.5)(input), input);
+    }
+
+    #[test]
+    fn try_jitter_with_bounds_rejects_invalid_bounds() {
+        assert!(try_jitter_with_bounds(0.5, 0.6).is_ok());
+        assert!(try_jitter_with_bounds(0.5, 0.5).is_ok());
+        assert!(try_jitter_with_bounds(0.5, f64::NAN).is_err());
+        assert!(try_jitter_with_bounds(-1.0, 0.5).is_err());
+        assert_eq!(
+            try_jitter_with_bounds(0.5, 0.4).err(),
+            Some(InvalidJitterBounds { min: 0.5, max: 0.4 })
+        );
+    }
+
+    #[test]
+    fn test_jitter_down() {
+        let input = Duration::from_millis(100);
+        let jitter = jitter_down(0.8);
+        for _ in 0..100 {
+            let jittered = jitter(input);
+            assert!(jittered <= input);
+            assert!(jittered >= input.mul_f64(0.8));
+        }
+        assert_eq!(jitter_down(f64::NAN)(input), input);
+        assert!(jitter_down(-1.0)(input) <= input);
+    }
+
     #[test]
     fn test_jitter_range() {
         let jitter = jitter_range(0..1)(Duration::from_millis(100));
         assert!(jitter.as_millis() <= 100);
         assert!(jitter.as_millis() != 100);
     }
+
+    #[test]
+    fn test_jitter_range_empty_is_identity() {
+        #[expect(clippy::reversed_empty_ranges)]
+        for jitter in [jitter_range(5..5), jitter_range(5..3)] {
+            assert_eq!(
+                jitter(Duration::from_millis(100)),
+                Duration::from_millis(100)
+            );
+        }
+    }
+
+    #[test]
+    fn test_jitter_range_single_point() {
+        let jitter = jitter_range(3..=3)(Duration::from_millis(100));
+        assert_eq!(jitter, Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_jitter_delta_is_non_decreasing() {
+        let raw: Vec<_> = ExponentialFactorBackoff::from_millis(100, 2.)
+            .take(10)
+            .collect();
+        let jittered: Vec<_> = jitter_delta(raw.clone()).collect();
+
+        assert_eq!(jittered.len(), raw.len());
+        assert!(jittered.windows(2).all(|w| w[0] <= w[1]));
+        assert!(raw.windows(2).zip(&jittered[1..]).all(|(w, j)| *j >= w[0]));
+        assert_ne!(jittered, raw);
+    }
 }
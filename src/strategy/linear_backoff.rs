@@ -1,5 +1,7 @@
 use std::time::Duration;
 
+use super::DurableStrategy;
+
 /// Linear backoff strategy that increases delay by a constant amount each retry
 ///
 /// > If `increment` is not defined then it will be equal to `initial`.
@@ -105,6 +107,40 @@ impl LinearBackoff {
         self.max_delay = Some(Duration::from_millis(millis));
         self
     }
+
+    /// Advances the strategy as if `n` delays were already yielded, so the first delay is
+    /// the one of attempt `n + 1`. Useful to resume a persisted retry state.
+    #[must_use]
+    pub const fn starting_at_attempt(mut self, n: u64) -> Self {
+        self.current_attempt = n;
+        self
+    }
+
+    /// The first delay, before the maximum delay cap.
+    #[must_use]
+    pub const fn first_delay(&self) -> Duration {
+        self.initial
+    }
+
+    /// The increment added on each retry.
+    #[must_use]
+    pub const fn delay_increment(&self) -> Duration {
+        self.increment
+    }
+
+    /// The maximum delay cap, if any.
+    #[must_use]
+    pub const fn max_delay_cap(&self) -> Option<Duration> {
+        self.max_delay
+    }
+}
+
+/// Defaults to `100ms`, increasing by `100ms` on each attempt, without max delay,
+/// so delays are `100ms`, `200ms`, `300ms`, ...
+impl Default for LinearBackoff {
+    fn default() -> Self {
+        Self::from_millis(100)
+    }
 }
 
 impl Iterator for LinearBackoff {
@@ -127,6 +163,55 @@ impl Iterator for LinearBackoff {
         self.current_attempt += 1;
         Some(delay)
     }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        self.current_attempt = self
+            .current_attempt
+            .saturating_add(u64::try_from(n).unwrap_or(u64::MAX));
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // the strategy never ends
+        (usize::MAX, None)
+    }
+}
+
+/// The running state of a [`LinearBackoff`], see [`DurableStrategy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinearBackoffState {
+    pub initial: Duration,
+    pub increment: Duration,
+    pub current_attempt: u64,
+    pub max_delay: Option<Duration>,
+}
+
+impl DurableStrategy for LinearBackoff {
+    type State = LinearBackoffState;
+
+    fn save_state(&self) -> Self::State {
+        LinearBackoffState {
+            initial: self.initial,
+            increment: self.increment,
+            current_attempt: self.current_attempt,
+            max_delay: self.max_delay,
+        }
+    }
+
+    fn restore_state(state: Self::State) -> Self {
+        let LinearBackoffState {
+            initial,
+            increment,
+            current_attempt,
+            max_delay,
+        } = state;
+        Self {
+            initial,
+            increment,
+            current_attempt,
+            max_delay,
+        }
+    }
 }
 
 #[cfg(test)]
@@ -179,4 +264,45 @@ mod tests {
         assert_eq!(s.next(), Some(Duration::from_millis(170)));
         assert_eq!(s.next(), Some(Duration::from_millis(200)));
     }
+
+    #[test]
+    fn starting_at_attempt_matches_tail() {
+        let strategy = LinearBackoff::from_millis(100)
+            .increment_millis(30)
+            .max_delay_millis(250);
+        let resumed = strategy.clone().starting_at_attempt(3).take(5);
+
+        assert!(resumed.eq(strategy.skip(3).take(5)));
+    }
+
+    #[test]
+    fn nth_matches_next() {
+        let mut skipped = LinearBackoff::from_millis(100).increment_millis(30);
+        let mut stepped = skipped.clone();
+        for _ in 0..5 {
+            stepped.next();
+        }
+
+        assert_eq!(skipped.nth(5), stepped.next());
+        assert_eq!(skipped.next(), stepped.next());
+    }
+
+    #[test]
+    fn default_sequence() {
+        let s = LinearBackoff::default().take(3);
+
+        assert!(s.eq([100, 200, 300].map(Duration::from_millis)));
+    }
+
+    #[test]
+    fn getters_return_construction_parameters() {
+        let mut s = LinearBackoff::from_secs(1)
+            .increment_millis(500)
+            .max_delay_millis(3000);
+        s.nth(3);
+
+        assert_eq!(s.first_delay(), Duration::from_secs(1));
+        assert_eq!(s.delay_increment(), Duration::from_millis(500));
+        assert_eq!(s.max_delay_cap(), Some(Duration::from_secs(3)));
+    }
 }
@@ -10,11 +10,7 @@ pub trait MaxInterval: Iterator<Item = Duration> {
     where
         Self: Sized,
     {
-        MaxIntervalIterator {
-            iter: self,
-            start: Instant::now(),
-            max_duration: Duration::from_millis(max_interval),
-        }
+        MaxIntervalIterator::new(self, Duration::from_millis(max_interval))
     }
 
     /// Applies a `max_duration` for a strategy. In `max_duration` from now,
@@ -24,11 +20,7 @@ pub trait MaxInterval: Iterator<Item = Duration> {
     where
         Self: Sized,
     {
-        MaxIntervalIterator {
-            iter: self,
-            start: Instant::now(),
-            max_duration,
-        }
+        MaxIntervalIterator::new(self, max_duration)
     }
 }
 
@@ -41,27 +33,85 @@ pub struct MaxIntervalIterator<I> {
     iter: I,
     start: Instant,
     max_duration: Duration,
+    cap_after: usize,
+    yielded: usize,
+}
+
+impl<I: Iterator<Item = Duration>> MaxIntervalIterator<I> {
+    /// Wraps any strategy, so that it stops yielding delays once `max_duration`
+    /// has elapsed since construction. Same as [`MaxInterval::max_duration`].
+    ///
+    /// Note that individual delays are not clamped, they are yielded unchanged
+    /// while the total time budget lasts. To cap single delays use `max_delay`.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tokio_retry2::strategy::MaxIntervalIterator;
+    ///
+    /// // a custom user strategy
+    /// let delays = [100, 2_000, 300].into_iter().map(Duration::from_millis);
+    ///
+    /// let mut strategy = MaxIntervalIterator::new(delays, Duration::from_secs(1));
+    ///
+    /// // within the 1 second budget, delays pass through untouched
+    /// assert_eq!(strategy.next(), Some(Duration::from_millis(100)));
+    /// assert_eq!(strategy.next(), Some(Duration::from_millis(2_000)));
+    /// assert_eq!(strategy.next(), Some(Duration::from_millis(300)));
+    /// assert_eq!(strategy.next(), None);
+    ///
+    /// // once the budget is spent, the strategy stops
+    /// let mut strategy = MaxIntervalIterator::new(std::iter::repeat(Duration::ZERO), Duration::ZERO);
+    /// std::thread::sleep(Duration::from_millis(1));
+    /// assert_eq!(strategy.next(), None);
+    /// ```
+    #[must_use]
+    pub fn new(iter: I, max_duration: Duration) -> Self {
+        Self {
+            iter,
+            start: Instant::now(),
+            max_duration,
+            cap_after: 0,
+            yielded: 0,
+        }
+    }
+
+    /// Exempts the first `n` delays from the `max_duration` budget: they are always
+    /// yielded, even once the budget is spent, and the budget is only checked from
+    /// delay `n + 1` onward. Delays themselves are never clamped, see `max_delay` for that.
+    ///
+    /// Useful to probe quickly with a few attempts before bounding the retry in time.
+    #[must_use]
+    pub const fn cap_after(mut self, n: usize) -> Self {
+        self.cap_after = n;
+        self
+    }
 }
 
 impl<I: Iterator<Item = Duration>> Iterator for MaxIntervalIterator<I> {
     type Item = Duration;
 
     fn next(&mut self) -> Option<Self::Item> {
-        if self.start.elapsed() > self.max_duration {
+        if self.yielded >= self.cap_after && self.start.elapsed() > self.max_duration {
             #[cfg(feature = "tracing")]
             tracing::warn!("`max_duration` reached, cancelling retry");
 
             None
         } else {
+            self.yielded = self.yielded.saturating_add(1);
             self.iter.next()
         }
     }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // the time budget may run out before the strategy does
+        (0, self.iter.size_hint().1)
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::strategy::FixedInterval;
+    use crate::strategy::{ExponentialBackoff, FixedInterval};
 
     #[tokio::test]
     async fn returns_none_after_max_interval_passes() {
@@ -82,4 +132,35 @@ mod tests {
         tokio::time::sleep(Duration::from_millis(100)).await;
         assert_eq!(s.next(), None);
     }
+
+    #[tokio::test]
+    async fn new_wraps_custom_iterator() {
+        let delays = [10, 20].into_iter().map(Duration::from_millis);
+        let mut s = MaxIntervalIterator::new(delays, Duration::from_millis(50));
+        assert_eq!(s.next(), Some(Duration::from_millis(10)));
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(s.next(), None);
+    }
+
+    #[tokio::test]
+    async fn cap_after_yields_first_delays_unbounded() {
+        let mut s = ExponentialBackoff::from_millis(10)
+            .max_duration(Duration::from_millis(5))
+            .cap_after(2);
+        tokio::time::sleep(Duration::from_millis(10)).await;
+        assert_eq!(s.next(), Some(Duration::from_millis(10)));
+        assert_eq!(s.next(), Some(Duration::from_millis(100)));
+        assert_eq!(s.next(), None);
+    }
+
+    #[tokio::test]
+    async fn cap_after_exempts_exactly_n_delays() {
+        for n in 0..4 {
+            let s = FixedInterval::from_millis(10)
+                .max_duration(Duration::ZERO)
+                .cap_after(n);
+            tokio::time::sleep(Duration::from_millis(1)).await;
+            assert_eq!(s.count(), n);
+        }
+    }
 }
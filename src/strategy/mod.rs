@@ -1,19 +1,59 @@
+mod alternating_interval;
+mod bounded;
+mod cap_reached;
+mod dyn_strategy;
 mod exponential_backoff;
 mod exponential_factor_backoff;
+mod exponential_rational_backoff;
 mod fibonacci_backoff;
 mod fixed_interval;
 #[cfg(feature = "jitter")]
 mod jitter;
 mod linear_backoff;
 mod max_interval;
+#[cfg(feature = "parse")]
+mod parse;
+mod phased_backoff;
+mod retry_schedule;
+mod rto_backoff;
+mod state;
+mod strategy_ext;
+mod total_delay;
 
 #[cfg(feature = "jitter")]
-pub use self::jitter::{jitter, jitter_range, jitter_with_bounds};
+pub use self::jitter::{
+    InvalidJitterBounds, JitterDelta, JitterWithinBudget, jitter, jitter_above, jitter_capped,
+    jitter_delta, jitter_down, jitter_fast, jitter_floored, jitter_from_hash, jitter_range,
+    jitter_with_bounds, jitter_within_budget, try_jitter_with_bounds,
+};
+#[cfg(feature = "parse")]
+pub use self::parse::{BoxedStrategy, ParseError, parse_duration, parse_strategy};
 pub use self::{
-    exponential_backoff::ExponentialBackoff,
-    exponential_factor_backoff::ExponentialFactorBackoff,
-    fibonacci_backoff::FibonacciBackoff,
-    fixed_interval::FixedInterval,
-    linear_backoff::LinearBackoff,
+    alternating_interval::AlternatingInterval,
+    bounded::Bounded,
+    cap_reached::{CappedStrategy, OnCapReached},
+    dyn_strategy::DynStrategy,
+    exponential_backoff::{ExponentialBackoff, ExponentialBackoffState},
+    exponential_factor_backoff::{
+        ExponentialFactorBackoff, ExponentialFactorBackoffState, InvalidFactor,
+    },
+    exponential_rational_backoff::{ExponentialRationalBackoff, ExponentialRationalBackoffState},
+    fibonacci_backoff::{FibonacciBackoff, FibonacciBackoffState},
+    fixed_interval::{FixedInterval, FixedIntervalState},
+    linear_backoff::{LinearBackoff, LinearBackoffState},
     max_interval::{MaxInterval, MaxIntervalIterator},
+    phased_backoff::PhasedBackoff,
+    retry_schedule::{RetrySchedule, RetryScheduleIter},
+    rto_backoff::{RtoBackoff, RtoBackoffState},
+    state::DurableStrategy,
+    strategy_ext::{
+        DampedToward, EnumerateAttempts, ImmediateFirst, InspectDelay, PlateauAfter, Quantize,
+        ScaleSequence, ScaledBy, Schedule, StrategyExt, TakeWhileDelay,
+    },
+    total_delay::expected_total_delay,
+};
+#[cfg(feature = "jitter")]
+pub use self::{
+    fixed_interval::JitteredFixedInterval,
+    strategy_ext::{Jittered, WithChaos, WithGiveupProbability},
 };
@@ -0,0 +1,317 @@
+use std::{error, fmt, str::FromStr};
+
+use tokio::time::Duration;
+
+use super::{ExponentialFactorBackoff, FibonacciBackoff, FixedInterval, LinearBackoff};
+
+/// A boxed strategy, as produced by [`parse_strategy`].
+pub type BoxedStrategy = Box<dyn Iterator<Item = Duration> + Send>;
+
+/// Error returned by [`parse_strategy`] when the input does not follow the grammar.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ParseError {
+    /// The input was empty.
+    Empty,
+    /// The strategy kind is not one of `fixed`, `exp`, `fib` or `linear`.
+    UnknownKind(String),
+    /// The initial delay is missing.
+    MissingDelay,
+    /// A duration could not be parsed.
+    InvalidDuration(String),
+    /// An option is malformed, duplicated or not supported by the strategy kind.
+    InvalidOption(String),
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Empty => write!(f, "empty strategy"),
+            Self::UnknownKind(kind) => write!(
+                f,
+                "unknown strategy `{kind}`, expected one of `fixed`, `exp`, `fib`, `linear`"
+            ),
+            Self::MissingDelay => write!(f, "missing initial delay, e.g. `fixed:500ms`"),
+            Self::InvalidDuration(duration) => write!(
+                f,
+                "invalid duration `{duration}`, expected an integer followed by `ms`, `s`, `m` or `h`"
+            ),
+            Self::InvalidOption(option) => write!(f, "invalid option `{option}`"),
+        }
+    }
+}
+
+impl error::Error for ParseError {}
+
+/// Parses a compact retry-policy string into a boxed strategy.
+///
+/// # Grammar
+///
+/// ```text
+/// strategy := kind ":" duration (":" option)*
+/// kind     := "fixed" | "exp" | "fib" | "linear"
+/// duration := integer ("ms" | "s" | "m" | "h")
+/// option   := "x" float        // growth factor, `exp` only, defaults to `2`
+///           | "+" duration     // increment, `linear` only, defaults to the initial delay
+///           | "max=" duration  // maximum single delay
+///           | "take=" integer  // maximum number of retries
+/// ```
+///
+/// `exp` maps to [`ExponentialFactorBackoff`], `fib` to [`FibonacciBackoff`],
+/// `linear` to [`LinearBackoff`] and `fixed` to [`FixedInterval`].
+///
+/// ```
+/// use std::time::Duration;
+/// use tokio_retry2::strategy::parse_strategy;
+///
+/// let mut strategy = parse_strategy("exp:100ms:x2:max=300ms:take=4").unwrap();
+/// assert_eq!(strategy.next(), Some(Duration::from_millis(100)));
+/// assert_eq!(strategy.next(), Some(Duration::from_millis(200)));
+/// assert_eq!(strategy.next(), Some(Duration::from_millis(300)));
+/// assert_eq!(strategy.next(), Some(Duration::from_millis(300)));
+/// assert_eq!(strategy.next(), None);
+/// ```
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] describing the first malformed part of the input.
+pub fn parse_strategy(s: &str) -> Result<BoxedStrategy, ParseError> {
+    let mut parts = s.trim().split(':');
+    let kind = parts.next().unwrap_or_default();
+    if kind.is_empty() {
+        return Err(ParseError::Empty);
+    }
+    if !matches!(kind, "fixed" | "exp" | "fib" | "linear") {
+        return Err(ParseError::UnknownKind(kind.to_string()));
+    }
+    let delay = parse_duration(parts.next().ok_or(ParseError::MissingDelay)?)?;
+
+    let mut factor = None;
+    let mut increment = None;
+    let mut max = None;
+    let mut take = None;
+    for option in parts {
+        let invalid = || ParseError::InvalidOption(option.to_string());
+        if let Some(value) = option.strip_prefix("max=") {
+            set_once(&mut max, parse_duration(value)?, option)?;
+        } else if let Some(value) = option.strip_prefix("take=") {
+            set_once(
+                &mut take,
+                value.parse::<usize>().map_err(|_| invalid())?,
+                option,
+            )?;
+        } else if let Some(value) = option.strip_prefix('x')
+            && kind == "exp"
+        {
+            let value = value
+                .parse::<f64>()
+                .ok()
+                .filter(|f| f.is_finite() && *f > 0.0)
+                .ok_or_else(invalid)?;
+            set_once(&mut factor, value, option)?;
+        } else if let Some(value) = option.strip_prefix('+')
+            && kind == "linear"
+        {
+            set_once(&mut increment, parse_duration(value)?, option)?;
+        } else {
+            return Err(invalid());
+        }
+    }
+
+    let strategy: BoxedStrategy = match kind {
+        "fixed" => {
+            let delay = max.map_or(delay, |max| delay.min(max));
+            Box::new(FixedInterval::new(delay))
+        }
+        "exp" => {
+            let mut strategy =
+                ExponentialFactorBackoff::from_millis(as_millis(delay), factor.unwrap_or(2.0));
+            if let Some(max) = max {
+                strategy = strategy.max_delay(max);
+            }
+            Box::new(strategy)
+        }
+        "fib" => {
+            let mut strategy = FibonacciBackoff::from_millis(as_millis(delay));
+            if let Some(max) = max {
+                strategy = strategy.max_delay(max);
+            }
+            Box::new(strategy)
+        }
+        _ => {
+            let mut strategy = LinearBackoff::new(delay).increment(increment.unwrap_or(delay));
+            if let Some(max) = max {
+                strategy = strategy.max_delay(max);
+            }
+            Box::new(strategy)
+        }
+    };
+
+    Ok(match take {
+        Some(n) => Box::new(strategy.take(n)),
+        None => strategy,
+    })
+}
+
+fn set_once<T>(slot: &mut Option<T>, value: T, option: &str) -> Result<(), ParseError> {
+    if slot.replace(value).is_some() {
+        return Err(ParseError::InvalidOption(option.to_string()));
+    }
+    Ok(())
+}
+
+fn as_millis(duration: Duration) -> u64 {
+    u64::try_from(duration.as_millis()).unwrap_or(u64::MAX)
+}
+
+/// Parses a duration made of an integer followed by a `ms`, `s`, `m` or `h` unit, as in
+/// the [`parse_strategy`] grammar, e.g. `"500ms"` or `"2s"`.
+///
+/// This is a small hand-written parser rather than `humantime`: compound durations such
+/// as `"1m 30s"`, fractions and other units are rejected.
+///
+/// [`FixedInterval`], [`LinearBackoff`] and [`FibonacciBackoff`] also implement [`FromStr`]
+/// with this format, the parsed duration being their first delay. [`ExponentialBackoff`](super::ExponentialBackoff)
+/// doesn't, since its base is also its growth factor, see [`parse_strategy`] instead:
+///
+/// ```
+/// use std::time::Duration;
+/// use tokio_retry2::strategy::{FixedInterval, parse_duration};
+///
+/// assert_eq!(parse_duration("1m"), Ok(Duration::from_secs(60)));
+///
+/// let mut strategy: FixedInterval = "500ms".parse().unwrap();
+/// assert_eq!(strategy.next(), Some(Duration::from_millis(500)));
+/// ```
+///
+/// # Errors
+///
+/// Returns [`ParseError::InvalidDuration`] if the input doesn't follow the format, or
+/// overflows a `Duration`.
+pub fn parse_duration(s: &str) -> Result<Duration, ParseError> {
+    let invalid = || ParseError::InvalidDuration(s.to_string());
+    let split = s.find(|c: char| !c.is_ascii_digit()).ok_or_else(invalid)?;
+    let (value, unit) = s.split_at(split);
+    let value = value.parse::<u64>().map_err(|_| invalid())?;
+    let secs = match unit {
+        "ms" => return Ok(Duration::from_millis(value)),
+        "s" => 1,
+        "m" => 60,
+        "h" => 3600,
+        _ => return Err(invalid()),
+    };
+    value
+        .checked_mul(secs)
+        .map(Duration::from_secs)
+        .ok_or_else(invalid)
+}
+
+impl FromStr for FixedInterval {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_duration(s.trim()).map(Self::new)
+    }
+}
+
+impl FromStr for LinearBackoff {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_duration(s.trim()).map(Self::new)
+    }
+}
+
+impl FromStr for FibonacciBackoff {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_duration(s.trim()).map(|duration| Self::from_millis(as_millis(duration)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn collect(s: &str) -> Vec<Duration> {
+        parse_strategy(s).unwrap().collect()
+    }
+
+    #[test]
+    fn parses_fixed() {
+        assert_eq!(
+            collect("fixed:500ms:take=2"),
+            vec![Duration::from_millis(500); 2]
+        );
+    }
+
+    #[test]
+    fn parses_exponential_with_max() {
+        let mut s = parse_strategy("exp:100ms:x2:max=10s").unwrap();
+        assert_eq!(s.next(), Some(Duration::from_millis(100)));
+        assert_eq!(s.next(), Some(Duration::from_millis(200)));
+        assert_eq!(s.nth(10), Some(Duration::from_secs(10)));
+    }
+
+    #[test]
+    fn parses_fibonacci_and_linear() {
+        assert_eq!(
+            collect("fib:1s:take=4"),
+            vec![
+                Duration::from_secs(1),
+                Duration::from_secs(1),
+                Duration::from_secs(2),
+                Duration::from_secs(3)
+            ]
+        );
+        assert_eq!(
+            collect("linear:100ms:+50ms:max=180ms:take=3"),
+            vec![
+                Duration::from_millis(100),
+                Duration::from_millis(150),
+                Duration::from_millis(180)
+            ]
+        );
+    }
+
+    #[test]
+    fn rejects_malformed_inputs() {
+        let err = |s| parse_strategy(s).err().unwrap();
+
+        assert_eq!(err(""), ParseError::Empty);
+        assert_eq!(err("jittery:1s"), ParseError::UnknownKind("jittery".into()));
+        assert_eq!(err("fixed"), ParseError::MissingDelay);
+        assert_eq!(err("fixed:10"), ParseError::InvalidDuration("10".into()));
+        assert_eq!(err("fixed:10y"), ParseError::InvalidDuration("10y".into()));
+        assert_eq!(err("fixed:1s:x2"), ParseError::InvalidOption("x2".into()));
+        assert_eq!(err("exp:1s:x-1"), ParseError::InvalidOption("x-1".into()));
+        assert_eq!(
+            err("exp:1s:take=2:take=3"),
+            ParseError::InvalidOption("take=3".into())
+        );
+        assert_eq!(
+            err("fixed:1s:max=soon").to_string(),
+            "invalid duration `soon`, expected an integer followed by `ms`, `s`, `m` or `h`"
+        );
+    }
+
+    #[test]
+    fn parses_base_durations() {
+        assert_eq!(parse_duration("500ms"), Ok(Duration::from_millis(500)));
+        assert_eq!(parse_duration("2s"), Ok(Duration::from_secs(2)));
+        assert_eq!(parse_duration("1m"), Ok(Duration::from_secs(60)));
+
+        let mut s: FixedInterval = "500ms".parse().unwrap();
+        assert_eq!(s.next(), Some(Duration::from_millis(500)));
+        let mut s: LinearBackoff = "2s".parse().unwrap();
+        assert_eq!(s.nth(1), Some(Duration::from_secs(4)));
+        let mut s: FibonacciBackoff = " 2s ".parse().unwrap();
+        assert_eq!(s.nth(2), Some(Duration::from_secs(4)));
+
+        assert_eq!(
+            "2 s".parse::<FixedInterval>().err(),
+            Some(ParseError::InvalidDuration("2 s".into()))
+        );
+    }
+}
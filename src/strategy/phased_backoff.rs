@@ -0,0 +1,136 @@
+use std::{
+    fmt,
+    ops::{Bound, RangeBounds},
+};
+
+use tokio::time::Duration;
+
+use super::DynStrategy;
+
+/// A retry strategy dispatching each delay to the strategy of the phase covering its
+/// attempt number, e.g. a few quick retries, then an exponential back-off, then a slow
+/// fixed interval.
+///
+/// Delays are numbered from `1`, the delay before the second attempt. Each phase
+/// strategy starts fresh at the first delay of its phase, and is only advanced by the
+/// delays of its phase. When phases overlap, the first one added wins. The strategy ends
+/// at the first delay not covered by any phase, e.g. beyond the last phase, or once the
+/// strategy of the current phase is exhausted.
+///
+/// ```
+/// use std::time::Duration;
+/// use tokio_retry2::strategy::{ExponentialBackoff, FixedInterval, PhasedBackoff};
+///
+/// let strategy = PhasedBackoff::new()
+///     .phase(1..=3, FixedInterval::from_millis(100))
+///     .phase(4..=10, ExponentialBackoff::from_millis(2).factor(100))
+///     .phase(11.., FixedInterval::from_secs(60));
+///
+/// let delays: Vec<_> = strategy.take(12).map(|delay| delay.as_millis()).collect();
+/// assert_eq!(
+///     delays,
+///     [100, 100, 100, 200, 400, 800, 1600, 3200, 6400, 12800, 60000, 60000]
+/// );
+/// ```
+#[derive(Clone, Default)]
+pub struct PhasedBackoff {
+    phases: Vec<Phase>,
+    yielded: usize,
+}
+
+#[derive(Clone)]
+struct Phase {
+    range: (Bound<usize>, Bound<usize>),
+    strategy: Box<dyn DynStrategy>,
+}
+
+impl PhasedBackoff {
+    /// Constructs a new strategy without any phase, yielding no delay.
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            phases: Vec::new(),
+            yielded: 0,
+        }
+    }
+
+    /// Adds a phase, using `strategy` for the delays whose attempt number is in `range`.
+    #[must_use]
+    pub fn phase<R, S>(mut self, range: R, strategy: S) -> Self
+    where
+        R: RangeBounds<usize>,
+        S: Iterator<Item = Duration> + Clone + Send + 'static,
+    {
+        self.phases.push(Phase {
+            range: (range.start_bound().cloned(), range.end_bound().cloned()),
+            strategy: Box::new(strategy),
+        });
+        self
+    }
+}
+
+impl fmt::Debug for PhasedBackoff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("PhasedBackoff")
+            .field(
+                "phases",
+                &self
+                    .phases
+                    .iter()
+                    .map(|phase| phase.range)
+                    .collect::<Vec<_>>(),
+            )
+            .field("yielded", &self.yielded)
+            .finish()
+    }
+}
+
+impl Iterator for PhasedBackoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        let attempt = self.yielded.saturating_add(1);
+        let phase = self
+            .phases
+            .iter_mut()
+            .find(|phase| phase.range.contains(&attempt))?;
+        let delay = phase.strategy.next_delay()?;
+        self.yielded = attempt;
+        Some(delay)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::{ExponentialBackoff, FixedInterval};
+
+    #[test]
+    fn dispatches_delays_at_phase_boundaries() {
+        let delays: Vec<_> = PhasedBackoff::new()
+            .phase(1..=3, FixedInterval::from_millis(100))
+            .phase(4..=10, ExponentialBackoff::from_millis(2).factor(100))
+            .phase(11.., FixedInterval::from_secs(60))
+            .take(12)
+            .collect();
+
+        assert_eq!(delays[2], Duration::from_millis(100));
+        assert_eq!(delays[3], Duration::from_millis(200));
+        assert_eq!(delays[9], Duration::from_millis(12_800));
+        assert_eq!(delays[10], Duration::from_secs(60));
+        assert_eq!(delays[11], Duration::from_secs(60));
+    }
+
+    #[test]
+    fn ends_beyond_last_phase_or_exhausted_phase() {
+        let s = PhasedBackoff::new().phase(1..3, FixedInterval::from_millis(10));
+        assert_eq!(s.count(), 2);
+
+        let s = PhasedBackoff::new()
+            .phase(1..=3, FixedInterval::from_millis(10).take(1))
+            .phase(4.., FixedInterval::from_millis(20));
+        assert_eq!(s.count(), 1);
+
+        assert_eq!(PhasedBackoff::new().next(), None);
+    }
+}
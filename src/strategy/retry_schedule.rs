@@ -0,0 +1,212 @@
+use tokio::time::Duration;
+
+use super::DynStrategy;
+use crate::future::accumulate;
+
+/// An owned retry schedule, bundling a strategy with optional jitter, maximum delay
+/// and maximum number of retries.
+///
+/// ```
+/// # #[cfg(feature = "jitter")]
+/// # {
+/// use std::time::Duration;
+/// use tokio_retry2::strategy::{ExponentialFactorBackoff, RetrySchedule, jitter};
+///
+/// let schedule = RetrySchedule::new(ExponentialFactorBackoff::from_millis(100, 2.))
+///     .jitter(jitter)
+///     .max_delay(Duration::from_secs(1))
+///     .take(5);
+///
+/// assert_eq!(schedule.len(), Some(5));
+/// assert_eq!(schedule.total(), Some(Duration::from_millis(2_500)));
+/// for delay in &schedule {
+///     assert!(delay <= Duration::from_secs(1));
+/// }
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct RetrySchedule {
+    strategy: Box<dyn DynStrategy>,
+    jitter: Option<fn(Duration) -> Duration>,
+    max_delay: Option<Duration>,
+    take: Option<usize>,
+}
+
+impl RetrySchedule {
+    /// Constructs a new schedule from a strategy, without jitter nor caps.
+    #[must_use]
+    pub fn new<S: DynStrategy + 'static>(strategy: S) -> Self {
+        Self {
+            strategy: Box::new(strategy),
+            jitter: None,
+            max_delay: None,
+            take: None,
+        }
+    }
+
+    /// Applies a jitter function, such as [`jitter`](crate::strategy::jitter), to each delay.
+    #[must_use]
+    pub const fn jitter(mut self, jitter: fn(Duration) -> Duration) -> Self {
+        self.jitter = Some(jitter);
+        self
+    }
+
+    /// Apply a maximum delay. No single retry delay, jittered or not, will be longer than this `Duration`.
+    #[must_use]
+    pub const fn max_delay(mut self, duration: Duration) -> Self {
+        self.max_delay = Some(duration);
+        self
+    }
+
+    /// Limits the schedule to `n` retries.
+    #[must_use]
+    pub const fn take(mut self, n: usize) -> Self {
+        self.take = Some(n);
+        self
+    }
+
+    /// Produces the delay sequence, starting from the beginning of the schedule.
+    #[must_use]
+    pub fn iter(&self) -> RetryScheduleIter {
+        RetryScheduleIter {
+            schedule: self.clone(),
+        }
+    }
+
+    /// The number of delays, when the schedule is bounded by [`RetrySchedule::take`].
+    ///
+    /// Computed in constant time when the strategy reports its exact length in
+    /// [`Iterator::size_hint`], like the built-in strategies, otherwise by counting the
+    /// delays, up to the `take` bound.
+    #[must_use]
+    pub fn len(&self) -> Option<usize> {
+        self.take?;
+        let iter = self.iter();
+        match iter.size_hint() {
+            (lower, Some(upper)) if lower == upper => Some(lower),
+            _ => Some(iter.count()),
+        }
+    }
+
+    /// Whether the schedule is bounded and yields no delay.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == Some(0)
+    }
+
+    /// The sum of all delays, without jitter, when the schedule is bounded by [`RetrySchedule::take`].
+    #[must_use]
+    pub fn total(&self) -> Option<Duration> {
+        self.take?;
+        let mut nominal = self.clone();
+        nominal.jitter = None;
+        Some(nominal.iter().fold(Duration::ZERO, accumulate))
+    }
+}
+
+impl IntoIterator for RetrySchedule {
+    type Item = Duration;
+    type IntoIter = RetryScheduleIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        RetryScheduleIter { schedule: self }
+    }
+}
+
+impl IntoIterator for &RetrySchedule {
+    type Item = Duration;
+    type IntoIter = RetryScheduleIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// The delay sequence of a [`RetrySchedule`].
+#[derive(Clone)]
+pub struct RetryScheduleIter {
+    schedule: RetrySchedule,
+}
+
+impl Iterator for RetryScheduleIter {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let schedule = &mut self.schedule;
+        if let Some(take) = schedule.take.as_mut() {
+            *take = take.checked_sub(1)?;
+        }
+        let delay = schedule.strategy.next_delay()?;
+        let delay = schedule.jitter.map_or(delay, |jitter| jitter(delay));
+        Some(schedule.max_delay.map_or(delay, |max| delay.min(max)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.schedule.strategy.delays_hint();
+        self.schedule.take.map_or((lower, upper), |take| {
+            (
+                lower.min(take),
+                Some(upper.map_or(take, |upper| upper.min(take))),
+            )
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::{FixedInterval, StrategyExt};
+
+    #[test]
+    fn bounded_schedule_len_and_total() {
+        let schedule = RetrySchedule::new(FixedInterval::from_millis(10))
+            .max_delay(Duration::from_millis(5))
+            .take(3);
+
+        assert_eq!(schedule.len(), Some(3));
+        assert_eq!(schedule.total(), Some(Duration::from_millis(15)));
+        assert_eq!(schedule.iter().count(), 3);
+        assert_eq!(schedule.into_iter().count(), 3);
+    }
+
+    #[test]
+    fn len_uses_the_strategy_length() {
+        let schedule = RetrySchedule::new(FixedInterval::from_millis(10)).take(usize::MAX);
+        assert_eq!(schedule.len(), Some(usize::MAX));
+
+        let schedule = RetrySchedule::new(FixedInterval::from_millis(10).take(2)).take(5);
+        assert_eq!(schedule.len(), Some(2));
+
+        let strategy = FixedInterval::from_millis(10)
+            .take(2)
+            .take_while_delay(Duration::from_millis(5));
+        assert_eq!(RetrySchedule::new(strategy).take(5).len(), Some(0));
+    }
+
+    #[test]
+    fn unbounded_schedule_has_no_len() {
+        let schedule = RetrySchedule::new(FixedInterval::from_millis(10));
+
+        assert_eq!(schedule.len(), None);
+        assert_eq!(schedule.total(), None);
+        assert!(!schedule.is_empty());
+    }
+
+    #[test]
+    #[cfg(feature = "jitter")]
+    fn exponential_jittered_schedule() {
+        use crate::strategy::{ExponentialFactorBackoff, jitter};
+
+        let schedule = RetrySchedule::new(ExponentialFactorBackoff::from_millis(100, 2.))
+            .jitter(jitter)
+            .take(5);
+
+        assert_eq!(schedule.len(), Some(5));
+        assert_eq!(schedule.total(), Some(Duration::from_millis(3_100)));
+        let nominal = [100, 200, 400, 800, 1600].map(Duration::from_millis);
+        for (delay, nominal) in schedule.iter().zip(nominal) {
+            assert!(delay >= nominal.mul_f64(0.5));
+            assert!(delay <= nominal.mul_f64(1.5));
+        }
+    }
+}
@@ -0,0 +1,187 @@
+use tokio::time::Duration;
+
+use super::DurableStrategy;
+
+/// A retry strategy modelled after TCP's retransmission timeout (RTO).
+///
+/// The delay starts at the initial RTO and doubles on every timeout, clamped between
+/// a minimum and a maximum RTO. Defaults follow [RFC 6298](https://www.rfc-editor.org/rfc/rfc6298)
+/// with the Linux bounds: 1s initial, 200ms minimum and 120s maximum.
+///
+/// # Example
+///
+/// ```
+/// use std::time::Duration;
+/// use tokio_retry2::strategy::RtoBackoff;
+///
+/// let mut strategy = RtoBackoff::default();
+///
+/// // 1s, 2s, 4s, .., 64s, 120s, 120s
+/// assert_eq!(strategy.next(), Some(Duration::from_secs(1)));
+/// assert_eq!(strategy.next(), Some(Duration::from_secs(2)));
+/// assert_eq!(strategy.nth(4), Some(Duration::from_secs(64)));
+/// assert_eq!(strategy.next(), Some(Duration::from_secs(120)));
+/// assert_eq!(strategy.next(), Some(Duration::from_secs(120)));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RtoBackoff {
+    current: Duration,
+    min_rto: Duration,
+    max_rto: Duration,
+}
+
+impl RtoBackoff {
+    /// The initial RTO recommended by RFC 6298.
+    pub const DEFAULT_INITIAL_RTO: Duration = Duration::from_secs(1);
+    /// The minimum RTO used by Linux.
+    pub const DEFAULT_MIN_RTO: Duration = Duration::from_millis(200);
+    /// The maximum RTO used by Linux.
+    pub const DEFAULT_MAX_RTO: Duration = Duration::from_secs(120);
+
+    /// Constructs a new RTO backoff starting at `initial_rto`, with the default bounds.
+    #[must_use]
+    pub const fn new(initial_rto: Duration) -> Self {
+        Self {
+            current: initial_rto,
+            min_rto: Self::DEFAULT_MIN_RTO,
+            max_rto: Self::DEFAULT_MAX_RTO,
+        }
+    }
+
+    /// Constructs a new RTO backoff starting at `millis`, with the default bounds.
+    #[must_use]
+    pub const fn from_millis(millis: u64) -> Self {
+        Self::new(Duration::from_millis(millis))
+    }
+
+    /// Set the minimum RTO. No single retry delay will be shorter than this.
+    #[must_use]
+    pub const fn min_rto(mut self, min_rto: Duration) -> Self {
+        self.min_rto = min_rto;
+        self
+    }
+
+    /// Set the maximum RTO. No single retry delay will be longer than this.
+    ///
+    /// When lower than the minimum RTO, the maximum RTO wins.
+    #[must_use]
+    pub const fn max_rto(mut self, max_rto: Duration) -> Self {
+        self.max_rto = max_rto;
+        self
+    }
+
+    /// The minimum RTO, below which no delay falls.
+    #[must_use]
+    pub const fn min_delay_floor(&self) -> Duration {
+        self.min_rto
+    }
+
+    /// The maximum RTO, always set.
+    #[must_use]
+    pub const fn max_delay_cap(&self) -> Option<Duration> {
+        Some(self.max_rto)
+    }
+
+    fn clamp(&self, rto: Duration) -> Duration {
+        rto.max(self.min_rto).min(self.max_rto)
+    }
+}
+
+impl Default for RtoBackoff {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_INITIAL_RTO)
+    }
+}
+
+impl Iterator for RtoBackoff {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Duration> {
+        let rto = self.clamp(self.current);
+        self.current = self.clamp(rto.saturating_mul(2));
+        Some(rto)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // the strategy never ends
+        (usize::MAX, None)
+    }
+}
+
+/// The running state of a [`RtoBackoff`], see [`DurableStrategy`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RtoBackoffState {
+    pub current: Duration,
+    pub min_rto: Duration,
+    pub max_rto: Duration,
+}
+
+impl DurableStrategy for RtoBackoff {
+    type State = RtoBackoffState;
+
+    fn save_state(&self) -> Self::State {
+        RtoBackoffState {
+            current: self.current,
+            min_rto: self.min_rto,
+            max_rto: self.max_rto,
+        }
+    }
+
+    fn restore_state(state: Self::State) -> Self {
+        let RtoBackoffState {
+            current,
+            min_rto,
+            max_rto,
+        } = state;
+        Self {
+            current,
+            min_rto,
+            max_rto,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn follows_default_rto_sequence() {
+        let s = RtoBackoff::default().take(10);
+
+        assert!(s.eq([1, 2, 4, 8, 16, 32, 64, 120, 120, 120].map(Duration::from_secs)));
+    }
+
+    #[test]
+    fn clamps_initial_rto_to_bounds() {
+        let mut s = RtoBackoff::from_millis(50);
+        assert_eq!(s.next(), Some(Duration::from_millis(200)));
+        assert_eq!(s.next(), Some(Duration::from_millis(400)));
+
+        let mut s = RtoBackoff::from_millis(50)
+            .min_rto(Duration::from_millis(10))
+            .max_rto(Duration::from_millis(150));
+        assert_eq!(s.next(), Some(Duration::from_millis(50)));
+        assert_eq!(s.next(), Some(Duration::from_millis(100)));
+        assert_eq!(s.next(), Some(Duration::from_millis(150)));
+        assert_eq!(s.next(), Some(Duration::from_millis(150)));
+    }
+
+    #[test]
+    fn getters_return_construction_parameters() {
+        let s = RtoBackoff::from_millis(50)
+            .min_rto(Duration::from_millis(10))
+            .max_rto(Duration::from_millis(150));
+
+        assert_eq!(s.min_delay_floor(), Duration::from_millis(10));
+        assert_eq!(s.max_delay_cap(), Some(Duration::from_millis(150)));
+    }
+
+    #[test]
+    fn saturates_when_unbounded() {
+        let mut s = RtoBackoff::new(Duration::MAX).max_rto(Duration::MAX);
+
+        assert_eq!(s.next(), Some(Duration::MAX));
+        assert_eq!(s.next(), Some(Duration::MAX));
+    }
+}
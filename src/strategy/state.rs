@@ -0,0 +1,29 @@
+/// Strategies whose running state can be snapshotted and restored, e.g. to persist the
+/// retry progress of a job across restarts.
+///
+/// Unlike the configuration of a strategy, the state also captures how far it advanced,
+/// so a restored strategy continues the sequence where the snapshot was taken.
+///
+/// The crate doesn't depend on `serde`, so the state types don't derive `Serialize` and
+/// `Deserialize` yet. They only have public plain fields, so they can be persisted in any
+/// format in the meantime, e.g. with a `serde` remote derive.
+///
+/// ```
+/// use tokio_retry2::strategy::{DurableStrategy, FibonacciBackoff};
+///
+/// let mut strategy = FibonacciBackoff::from_millis(10);
+/// strategy.nth(3);
+///
+/// let mut restored = FibonacciBackoff::restore_state(strategy.save_state());
+/// assert_eq!(restored.next(), strategy.next());
+/// ```
+pub trait DurableStrategy {
+    /// The snapshot of the running state of the strategy.
+    type State;
+
+    /// Snapshots the running state of the strategy.
+    fn save_state(&self) -> Self::State;
+
+    /// Restores a strategy from a snapshot taken by [`DurableStrategy::save_state`].
+    fn restore_state(state: Self::State) -> Self;
+}
@@ -0,0 +1,814 @@
+#[cfg(feature = "jitter")]
+use rand::{Rng, SeedableRng, rngs::StdRng};
+use tokio::time::{Duration, Instant};
+
+/// Extension combinators available on every strategy.
+pub trait StrategyExt: Iterator<Item = Duration> {
+    /// Multiplies each yielded delay by the value returned from `f` at iteration time,
+    /// turning a static strategy into one reacting to a live signal (e.g. queue depth).
+    ///
+    /// Negative or `NaN` factors yield `Duration::ZERO`, overflowing results saturate
+    /// at `Duration::MAX`.
+    fn scaled_by<F>(self, f: F) -> ScaledBy<Self, F>
+    where
+        Self: Sized,
+        F: FnMut() -> f64,
+    {
+        ScaledBy { iter: self, f }
+    }
+
+    /// Multiplies the `i`-th delay by `factors[i]`, repeating the last factor once the
+    /// table is exhausted, e.g. `vec![1.0, 1.0, 2.0, 4.0]`.
+    ///
+    /// An empty table leaves the delays unchanged. Invalid factors are handled as in
+    /// [`StrategyExt::scaled_by`].
+    fn scale_sequence(self, factors: Vec<f64>) -> ScaleSequence<Self>
+    where
+        Self: Sized,
+    {
+        ScaleSequence {
+            iter: self,
+            factors,
+            index: 0,
+        }
+    }
+
+    /// Rounds each delay to the nearest multiple of `step`, rounding halves up, e.g. to
+    /// align retries on a coarse timer wheel.
+    ///
+    /// Delays shorter than half a `step` round to zero, see [`Quantize::at_least_step`]
+    /// to round them up to one `step` instead. A zero `step` leaves the delays unchanged.
+    fn quantize(self, step: Duration) -> Quantize<Self>
+    where
+        Self: Sized,
+    {
+        Quantize {
+            iter: self,
+            step,
+            at_least_step: false,
+        }
+    }
+
+    /// Accumulates the delays into absolute instants from `start`, for scheduling
+    /// retries against a clock instead of sleeping between attempts.
+    ///
+    /// The `n`-th instant is `start` plus the sum of the first `n` delays. The schedule
+    /// ends early if an instant can't be represented.
+    fn schedule_from(self, start: Instant) -> Schedule<Self>
+    where
+        Self: Sized,
+    {
+        Schedule {
+            iter: self,
+            at: start,
+        }
+    }
+
+    /// Yields delays while they are lower than or equal to `max`, and stops
+    /// retrying at the first delay exceeding it.
+    fn take_while_delay(self, max: Duration) -> TakeWhileDelay<Self>
+    where
+        Self: Sized,
+    {
+        TakeWhileDelay {
+            iter: Some(self),
+            max,
+        }
+    }
+
+    /// Yields the first `n` delays of the strategy, then `delay` forever, regardless of
+    /// the underlying strategy.
+    ///
+    /// The strategy still ends early if it runs out within the first `n` delays.
+    fn plateau_after(self, n: usize, delay: Duration) -> PlateauAfter<Self>
+    where
+        Self: Sized,
+    {
+        PlateauAfter {
+            iter: self,
+            remaining: n,
+            delay,
+        }
+    }
+
+    /// Yields `n` zero delays before the delays of the strategy, so the first `n` retries
+    /// happen right away, e.g. for races likely to resolve on an immediate retry.
+    fn immediate_first(self, n: usize) -> ImmediateFirst<Self>
+    where
+        Self: Sized,
+    {
+        ImmediateFirst {
+            iter: self,
+            remaining: n,
+        }
+    }
+
+    /// Pairs each delay with its attempt index, starting at `1` for the delay
+    /// before the first retry.
+    ///
+    /// Unlike [`Iterator::enumerate`], indexes are 1-based, matching the number of
+    /// the retry the delay precedes.
+    fn enumerate_attempts(self) -> EnumerateAttempts<Self>
+    where
+        Self: Sized,
+    {
+        EnumerateAttempts {
+            iter: self,
+            attempt: 0,
+        }
+    }
+
+    /// Dampens the delays toward `target`: the first delay is the one of the strategy,
+    /// then each delay moves the previous one toward the target by `alpha`, i.e.
+    /// `next = current + alpha * (target - current)`, converging monotonically.
+    ///
+    /// The strategy only sets the first delay and the number of delays. `alpha` is
+    /// clamped to `[0.0, 1.0]`, `0.0` keeping the first delay and `1.0` jumping to the
+    /// target, and `NaN` means `0.0`.
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tokio_retry2::strategy::{FixedInterval, StrategyExt};
+    ///
+    /// let delays: Vec<_> = FixedInterval::from_millis(100)
+    ///     .damped_toward(Duration::from_millis(20), 0.5)
+    ///     .take(4)
+    ///     .map(|delay| delay.as_millis())
+    ///     .collect();
+    /// assert_eq!(delays, [100, 60, 40, 30]);
+    /// ```
+    fn damped_toward(self, target: Duration, alpha: f64) -> DampedToward<Self>
+    where
+        Self: Sized,
+    {
+        DampedToward {
+            iter: self,
+            target,
+            alpha: if alpha.is_nan() {
+                0.0
+            } else {
+                alpha.clamp(0.0, 1.0)
+            },
+            current: None,
+        }
+    }
+
+    /// Calls `f` with each delay before yielding it, like [`Iterator::inspect`] but with
+    /// a nameable type, to tap the delay stream without implementing [`Notify`](crate::Notify).
+    fn inspect_delay<F>(self, f: F) -> InspectDelay<Self, F>
+    where
+        Self: Sized,
+        F: FnMut(Duration),
+    {
+        InspectDelay { iter: self, f }
+    }
+
+    /// Applies [`jitter`](crate::strategy::jitter) to every delay, scaling it by a random
+    /// factor in `[0.5, 1.5)`, available on every strategy including the float based
+    /// [`ExponentialFactorBackoff`](crate::strategy::ExponentialFactorBackoff).
+    ///
+    /// Unlike `.map(jitter)`, a jittered delay overflowing `Duration::MAX` saturates
+    /// instead of panicking.
+    #[cfg(feature = "jitter")]
+    fn jitter(self) -> Jittered<Self>
+    where
+        Self: Sized,
+    {
+        Jittered { iter: self }
+    }
+
+    /// Perturbs every delay by a random skew in `[-skew, +skew]`, drawn from a generator
+    /// seeded with `rng_seed`, for fault injection, e.g. to simulate clock jitter and
+    /// scheduler noise in resilience tests.
+    ///
+    /// Unlike [`StrategyExt::jitter`], the same seed always yields the same delays.
+    /// Skewed delays saturate at `Duration::ZERO` and `Duration::MAX`.
+    #[cfg(feature = "jitter")]
+    fn with_chaos(self, skew: Duration, rng_seed: u64) -> WithChaos<Self>
+    where
+        Self: Sized,
+    {
+        WithChaos {
+            iter: self,
+            skew,
+            rng: StdRng::seed_from_u64(rng_seed),
+        }
+    }
+
+    /// Before yielding each delay, gives up retrying with probability `p(n)`, where `n`
+    /// is the number of delays yielded so far, for adaptive load shedding.
+    ///
+    /// Probabilities are clamped to `[0.0, 1.0]`, and `NaN` never gives up.
+    #[cfg(feature = "jitter")]
+    fn with_giveup_probability<P>(self, p: P) -> WithGiveupProbability<Self, P, StdRng>
+    where
+        Self: Sized,
+        P: Fn(usize) -> f64,
+    {
+        self.with_giveup_probability_rng(p, StdRng::from_os_rng())
+    }
+
+    /// Same as [`StrategyExt::with_giveup_probability`], rolling with the given `rng`,
+    /// e.g. a seeded one for deterministic behavior.
+    #[cfg(feature = "jitter")]
+    fn with_giveup_probability_rng<P, R>(self, p: P, rng: R) -> WithGiveupProbability<Self, P, R>
+    where
+        Self: Sized,
+        P: Fn(usize) -> f64,
+        R: Rng,
+    {
+        WithGiveupProbability {
+            iter: Some(self),
+            p,
+            rng,
+            yielded: 0,
+        }
+    }
+}
+
+impl<I> StrategyExt for I where I: Iterator<Item = Duration> {}
+
+/// A strategy wrapper scaling each delay by a live factor,
+/// created by [`StrategyExt::scaled_by`] function.
+#[derive(Debug, Clone)]
+pub struct ScaledBy<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I: Iterator<Item = Duration>, F: FnMut() -> f64> Iterator for ScaledBy<I, F> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let delay = self.iter.next()?;
+        Some(scale(delay, (self.f)()))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// A strategy wrapper scaling each delay by a per-attempt factor,
+/// created by [`StrategyExt::scale_sequence`] function.
+#[derive(Debug, Clone)]
+pub struct ScaleSequence<I> {
+    iter: I,
+    factors: Vec<f64>,
+    index: usize,
+}
+
+impl<I: Iterator<Item = Duration>> Iterator for ScaleSequence<I> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let delay = self.iter.next()?;
+        let Some(factor) = self.factors.get(self.index).or_else(|| self.factors.last()) else {
+            return Some(delay);
+        };
+        self.index = self.index.saturating_add(1);
+        Some(scale(delay, *factor))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// A strategy wrapper rounding delays to a grid,
+/// created by [`StrategyExt::quantize`] function.
+#[derive(Debug, Clone)]
+pub struct Quantize<I> {
+    iter: I,
+    step: Duration,
+    at_least_step: bool,
+}
+
+impl<I> Quantize<I> {
+    /// Rounds delays shorter than half a step up to one step, instead of zero.
+    #[must_use]
+    pub const fn at_least_step(mut self) -> Self {
+        self.at_least_step = true;
+        self
+    }
+}
+
+impl<I: Iterator<Item = Duration>> Iterator for Quantize<I> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let delay = self.iter.next()?;
+        let step = self.step.as_nanos();
+        if step == 0 {
+            return Some(delay);
+        }
+        let steps = (delay.as_nanos() + step / 2) / step;
+        let steps = if self.at_least_step {
+            steps.max(1)
+        } else {
+            steps
+        };
+        let nanos = steps.saturating_mul(step);
+        Some(u64::try_from(nanos).map_or(Duration::MAX, Duration::from_nanos))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+pub(super) fn scale(delay: Duration, factor: f64) -> Duration {
+    if factor.is_nan() || factor <= 0.0 {
+        return Duration::ZERO;
+    }
+    Duration::try_from_secs_f64(delay.as_secs_f64() * factor).unwrap_or(Duration::MAX)
+}
+
+/// A strategy wrapper dampening delays toward a target,
+/// created by [`StrategyExt::damped_toward`] function.
+#[derive(Debug, Clone)]
+pub struct DampedToward<I> {
+    iter: I,
+    target: Duration,
+    alpha: f64,
+    current: Option<Duration>,
+}
+
+impl<I: Iterator<Item = Duration>> Iterator for DampedToward<I> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let delay = self.iter.next()?;
+        let next = self.current.map_or(delay, |current| {
+            let (current, target) = (current.as_secs_f64(), self.target.as_secs_f64());
+            Duration::try_from_secs_f64(self.alpha.mul_add(target - current, current))
+                .unwrap_or(self.target)
+        });
+        self.current = Some(next);
+        Some(next)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// A strategy wrapper yielding absolute instants,
+/// created by [`StrategyExt::schedule_from`] function.
+#[derive(Debug, Clone)]
+pub struct Schedule<I> {
+    iter: I,
+    at: Instant,
+}
+
+impl<I: Iterator<Item = Duration>> Iterator for Schedule<I> {
+    type Item = Instant;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.at = self.at.checked_add(self.iter.next()?)?;
+        Some(self.at)
+    }
+}
+
+/// A strategy wrapper stopping at the first delay above a threshold,
+/// created by [`StrategyExt::take_while_delay`] function.
+#[derive(Debug, Clone)]
+pub struct TakeWhileDelay<I> {
+    iter: Option<I>,
+    max: Duration,
+}
+
+impl<I: Iterator<Item = Duration>> Iterator for TakeWhileDelay<I> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let delay = self
+            .iter
+            .as_mut()?
+            .next()
+            .filter(|delay| *delay <= self.max);
+        if delay.is_none() {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("delay above `take_while_delay` threshold, cancelling retry");
+            self.iter = None;
+        }
+        delay
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        // any delay may exceed the threshold
+        self.iter
+            .as_ref()
+            .map_or((0, Some(0)), |iter| (0, iter.size_hint().1))
+    }
+}
+
+/// A strategy wrapper holding a constant delay after `n` delays,
+/// created by [`StrategyExt::plateau_after`] function.
+#[derive(Debug, Clone)]
+pub struct PlateauAfter<I> {
+    iter: I,
+    remaining: usize,
+    delay: Duration,
+}
+
+impl<I: Iterator<Item = Duration>> Iterator for PlateauAfter<I> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return Some(self.delay);
+        }
+        let delay = self.iter.next()?;
+        self.remaining -= 1;
+        Some(delay)
+    }
+}
+
+/// A strategy wrapper prepending zero delays,
+/// created by [`StrategyExt::immediate_first`] function.
+#[derive(Debug, Clone)]
+pub struct ImmediateFirst<I> {
+    iter: I,
+    remaining: usize,
+}
+
+impl<I: Iterator<Item = Duration>> Iterator for ImmediateFirst<I> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return self.iter.next();
+        }
+        self.remaining -= 1;
+        Some(Duration::ZERO)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let (lower, upper) = self.iter.size_hint();
+        (
+            lower.saturating_add(self.remaining),
+            upper.and_then(|upper| upper.checked_add(self.remaining)),
+        )
+    }
+}
+
+/// A strategy wrapper pairing delays with their 1-based attempt index,
+/// created by [`StrategyExt::enumerate_attempts`] function.
+#[derive(Debug, Clone)]
+pub struct EnumerateAttempts<I> {
+    iter: I,
+    attempt: usize,
+}
+
+impl<I: Iterator<Item = Duration>> Iterator for EnumerateAttempts<I> {
+    type Item = (usize, Duration);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let delay = self.iter.next()?;
+        self.attempt = self.attempt.saturating_add(1);
+        Some((self.attempt, delay))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// A strategy wrapper calling a closure with each delay,
+/// created by [`StrategyExt::inspect_delay`] function.
+#[derive(Debug, Clone)]
+pub struct InspectDelay<I, F> {
+    iter: I,
+    f: F,
+}
+
+impl<I: Iterator<Item = Duration>, F: FnMut(Duration)> Iterator for InspectDelay<I, F> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let delay = self.iter.next()?;
+        (self.f)(delay);
+        Some(delay)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// A strategy wrapper jittering each delay,
+/// created by [`StrategyExt::jitter`] function.
+#[cfg(feature = "jitter")]
+#[derive(Debug, Clone)]
+pub struct Jittered<I> {
+    iter: I,
+}
+
+#[cfg(feature = "jitter")]
+impl<I: Iterator<Item = Duration>> Iterator for Jittered<I> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let delay = self.iter.next()?;
+        Some(scale(delay, rand::random::<f64>() + 0.5))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// A strategy wrapper skewing each delay with a seeded generator,
+/// created by [`StrategyExt::with_chaos`] function.
+#[cfg(feature = "jitter")]
+#[derive(Debug, Clone)]
+pub struct WithChaos<I> {
+    iter: I,
+    skew: Duration,
+    rng: StdRng,
+}
+
+#[cfg(feature = "jitter")]
+impl<I: Iterator<Item = Duration>> Iterator for WithChaos<I> {
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let delay = self.iter.next()?;
+        let offset = self
+            .rng
+            .random_range(Duration::ZERO..=self.skew.saturating_mul(2));
+        Some(if offset >= self.skew {
+            delay.saturating_add(offset.saturating_sub(self.skew))
+        } else {
+            delay.saturating_sub(self.skew.saturating_sub(offset))
+        })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+/// A strategy wrapper randomly giving up retrying,
+/// created by [`StrategyExt::with_giveup_probability`] function.
+#[cfg(feature = "jitter")]
+#[derive(Debug, Clone)]
+pub struct WithGiveupProbability<I, P, R> {
+    iter: Option<I>,
+    p: P,
+    rng: R,
+    yielded: usize,
+}
+
+#[cfg(feature = "jitter")]
+impl<I, P, R> Iterator for WithGiveupProbability<I, P, R>
+where
+    I: Iterator<Item = Duration>,
+    P: Fn(usize) -> f64,
+    R: Rng,
+{
+    type Item = Duration;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let iter = self.iter.as_mut()?;
+        if self.rng.random::<f64>() < (self.p)(self.yielded) {
+            #[cfg(feature = "tracing")]
+            tracing::warn!("giving up retry at random");
+            self.iter = None;
+            return None;
+        }
+        let delay = iter.next()?;
+        self.yielded += 1;
+        Some(delay)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter
+            .as_ref()
+            .map_or((0, Some(0)), |iter| (0, iter.size_hint().1))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::{ExponentialBackoff, FixedInterval, LinearBackoff};
+
+    #[test]
+    fn scales_delays_by_live_factor() {
+        let mut factors = [2.0, 0.5].into_iter();
+        let mut s = FixedInterval::from_millis(100)
+            .scaled_by(move || factors.next().unwrap_or(1.0))
+            .take(3);
+
+        assert_eq!(s.next(), Some(Duration::from_millis(200)));
+        assert_eq!(s.next(), Some(Duration::from_millis(50)));
+        assert_eq!(s.next(), Some(Duration::from_millis(100)));
+        assert_eq!(s.next(), None);
+    }
+
+    #[test]
+    fn scaled_by_handles_invalid_factors() {
+        let mut s = FixedInterval::from_millis(100).scaled_by(|| -1.0);
+        assert_eq!(s.next(), Some(Duration::ZERO));
+
+        let mut s = FixedInterval::from_millis(100).scaled_by(|| f64::NAN);
+        assert_eq!(s.next(), Some(Duration::ZERO));
+
+        let mut s = FixedInterval::from_millis(100).scaled_by(|| f64::INFINITY);
+        assert_eq!(s.next(), Some(Duration::MAX));
+    }
+
+    #[test]
+    fn scales_delays_by_factor_sequence() {
+        let s = FixedInterval::from_millis(100)
+            .scale_sequence(vec![1.0, 2.0])
+            .take(4);
+
+        assert!(s.eq([100, 200, 200, 200].map(Duration::from_millis)));
+
+        let mut s = FixedInterval::from_millis(100).scale_sequence(Vec::new());
+        assert_eq!(s.next(), Some(Duration::from_millis(100)));
+    }
+
+    #[test]
+    fn quantizes_to_nearest_step() {
+        let s = [10, 24, 25, 74, 75, 130]
+            .map(Duration::from_millis)
+            .into_iter()
+            .quantize(Duration::from_millis(50));
+
+        assert!(s.eq([0, 0, 50, 50, 100, 150].map(Duration::from_millis)));
+    }
+
+    #[test]
+    fn quantizes_at_least_one_step() {
+        let s = [0, 10, 74, 75]
+            .map(Duration::from_millis)
+            .into_iter()
+            .quantize(Duration::from_millis(50))
+            .at_least_step();
+
+        assert!(s.eq([50, 50, 50, 100].map(Duration::from_millis)));
+
+        let mut s = FixedInterval::from_millis(10).quantize(Duration::ZERO);
+        assert_eq!(s.next(), Some(Duration::from_millis(10)));
+    }
+
+    #[test]
+    fn schedules_cumulative_instants() {
+        let start = Instant::now();
+        let mut s = LinearBackoff::from_millis(100).take(3).schedule_from(start);
+
+        assert_eq!(s.next(), Some(start + Duration::from_millis(100)));
+        assert_eq!(s.next(), Some(start + Duration::from_millis(300)));
+        assert_eq!(s.next(), Some(start + Duration::from_millis(600)));
+        assert_eq!(s.next(), None);
+    }
+
+    #[test]
+    fn schedule_ends_on_overflow() {
+        let mut s = FixedInterval::new(Duration::MAX).schedule_from(Instant::now());
+        assert_eq!(s.next(), None);
+    }
+
+    #[test]
+    fn takes_while_delay_below_max() {
+        let mut s = ExponentialBackoff::from_millis(2).take_while_delay(Duration::from_millis(8));
+
+        assert_eq!(s.next(), Some(Duration::from_millis(2)));
+        assert_eq!(s.next(), Some(Duration::from_millis(4)));
+        assert_eq!(s.next(), Some(Duration::from_millis(8)));
+        assert_eq!(s.next(), None);
+        assert_eq!(s.next(), None);
+    }
+
+    #[test]
+    fn plateaus_after_n_delays() {
+        let s = ExponentialBackoff::from_millis(2)
+            .plateau_after(3, Duration::from_millis(5))
+            .take(6);
+
+        assert!(s.eq([2, 4, 8, 5, 5, 5].map(Duration::from_millis)));
+    }
+
+    #[test]
+    fn plateau_ends_with_short_strategy() {
+        let mut s = FixedInterval::from_millis(10)
+            .take(1)
+            .plateau_after(2, Duration::from_millis(5));
+
+        assert_eq!(s.next(), Some(Duration::from_millis(10)));
+        assert_eq!(s.next(), None);
+    }
+
+    #[test]
+    fn immediate_first_prepends_zero_delays() {
+        let s = ExponentialBackoff::from_millis(10)
+            .take(2)
+            .immediate_first(2);
+
+        assert_eq!(s.size_hint().1, Some(4));
+        assert!(s.eq([0, 0, 10, 100].map(Duration::from_millis)));
+    }
+
+    #[test]
+    fn enumerates_attempts_from_one() {
+        let s = ExponentialBackoff::from_millis(10)
+            .take(3)
+            .enumerate_attempts();
+
+        assert!(s.eq([
+            (1, Duration::from_millis(10)),
+            (2, Duration::from_millis(100)),
+            (3, Duration::from_secs(1)),
+        ]));
+    }
+
+    #[test]
+    fn inspects_each_delay() {
+        let mut inspected = Vec::new();
+        let delays: Vec<_> = LinearBackoff::from_millis(10)
+            .take(3)
+            .inspect_delay(|delay| inspected.push(delay))
+            .collect();
+
+        assert_eq!(inspected, [10, 20, 30].map(Duration::from_millis));
+        assert_eq!(inspected, delays);
+    }
+
+    #[test]
+    #[cfg(feature = "jitter")]
+    fn gives_up_deterministically_with_seeded_rng() {
+        let run = || {
+            FixedInterval::from_millis(10)
+                .with_giveup_probability_rng(
+                    |n| [0.0, 0.3, 0.6].get(n).copied().unwrap_or(1.0),
+                    StdRng::seed_from_u64(42),
+                )
+                .count()
+        };
+        let retries = run();
+
+        assert!((1..=3).contains(&retries));
+        assert_eq!(run(), retries);
+    }
+
+    #[test]
+    #[cfg(feature = "jitter")]
+    fn giveup_probability_bounds() {
+        let mut s = FixedInterval::from_millis(10).with_giveup_probability(|_| 1.0);
+        assert_eq!(s.next(), None);
+
+        let s = FixedInterval::from_millis(10).with_giveup_probability(|_| f64::NAN);
+        assert_eq!(s.take(100).count(), 100);
+    }
+
+    #[test]
+    #[cfg(feature = "jitter")]
+    fn chaos_is_reproducible_under_seed() {
+        let skew = Duration::from_millis(20);
+        let chaos = |seed| {
+            FixedInterval::from_millis(100)
+                .with_chaos(skew, seed)
+                .take(50)
+                .collect::<Vec<_>>()
+        };
+
+        let delays = chaos(42);
+        assert_eq!(delays, chaos(42));
+        assert_ne!(delays, chaos(7));
+        assert!(delays.iter().all(|delay| delay.as_millis() >= 80));
+        assert!(delays.iter().all(|delay| delay.as_millis() <= 120));
+        assert!(
+            FixedInterval::from_millis(5)
+                .with_chaos(skew, 42)
+                .take(50)
+                .all(|delay| delay.as_millis() <= 25)
+        );
+    }
+
+    #[test]
+    fn damped_toward_converges_monotonically() {
+        let target = Duration::from_millis(500);
+        for start in [10_000, 10] {
+            let delays: Vec<_> = FixedInterval::from_millis(start)
+                .damped_toward(target, 0.3)
+                .take(30)
+                .collect();
+
+            assert_eq!(delays[0], Duration::from_millis(start));
+            for pair in delays.windows(2) {
+                let (previous, next) = (pair[0].abs_diff(target), pair[1].abs_diff(target));
+                assert!(next <= previous);
+            }
+            assert!(delays[29].abs_diff(target) < Duration::from_millis(1));
+            if start > 500 {
+                assert!(delays.iter().all(|delay| *delay >= target));
+            } else {
+                assert!(delays.iter().all(|delay| *delay <= target));
+            }
+        }
+    }
+}
@@ -0,0 +1,70 @@
+use tokio::time::Duration;
+
+use crate::future::accumulate;
+
+/// Sums the delays of a bounded strategy, e.g. to warn that retrying could take up to
+/// the returned duration. The sum saturates at `Duration::MAX`.
+///
+/// To guard against infinite strategies, returns `None` unless the strategy reports an
+/// upper bound in its [`Iterator::size_hint`], so bound it first with `.take(n)` or
+/// [`with_max_retries`](crate::strategy::ExponentialBackoff::with_max_retries).
+///
+/// ```
+/// use std::time::Duration;
+/// use tokio_retry2::strategy::{ExponentialBackoff, expected_total_delay};
+///
+/// let strategy = ExponentialBackoff::from_millis(10).take(3);
+/// assert_eq!(expected_total_delay(strategy), Some(Duration::from_millis(1_110)));
+///
+/// assert_eq!(expected_total_delay(ExponentialBackoff::from_millis(10)), None);
+/// ```
+pub fn expected_total_delay<I: IntoIterator<Item = Duration>>(strategy: I) -> Option<Duration> {
+    let strategy = strategy.into_iter();
+    strategy.size_hint().1?;
+    Some(strategy.fold(Duration::ZERO, accumulate))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::strategy::{
+        DynStrategy, ExponentialBackoff, FixedInterval, MaxInterval, StrategyExt,
+    };
+
+    #[test]
+    fn sums_bounded_exponential() {
+        let strategy = ExponentialBackoff::from_millis(2).with_max_retries(4);
+
+        assert_eq!(
+            expected_total_delay(strategy),
+            Some(Duration::from_millis(30))
+        );
+    }
+
+    #[test]
+    fn rejects_unbounded_strategies() {
+        assert_eq!(expected_total_delay(FixedInterval::from_millis(10)), None);
+        assert_eq!(
+            expected_total_delay(FixedInterval::new(Duration::MAX).take(2)),
+            Some(Duration::MAX)
+        );
+    }
+
+    #[test]
+    fn sums_wrapped_bounded_strategies() {
+        let strategy = || FixedInterval::from_millis(10).take(3);
+        let expected = Some(Duration::from_millis(30));
+
+        assert_eq!(expected_total_delay(strategy().scaled_by(|| 1.0)), expected);
+        assert_eq!(
+            expected_total_delay(strategy().take_while_delay(Duration::from_secs(1))),
+            expected
+        );
+        assert_eq!(
+            expected_total_delay(strategy().max_interval(1_000)),
+            expected
+        );
+        let boxed: Box<dyn DynStrategy> = Box::new(strategy());
+        assert_eq!(expected_total_delay(boxed), expected);
+    }
+}
@@ -0,0 +1,212 @@
+use std::{
+    future::{Future, poll_fn},
+    pin::Pin,
+    sync::{Arc, Mutex, MutexGuard, PoisonError},
+    task::{Context, Poll, ready},
+};
+
+use pin_project::pin_project;
+use tokio::time::{Duration, Sleep, sleep_until};
+
+use crate::{
+    action::Action,
+    error::Error as RetryError,
+    future::{Retry, deadline_after},
+};
+
+/// A fallible stream of chunks, resumed by [`Retry::spawn_resumable_stream`] function.
+///
+/// Mirrors `futures::TryStream`, without depending on `futures`: a `Stream` of
+/// `Result<T, RetryError<E>>` is adapted by forwarding `poll_next`.
+pub trait ChunkStream {
+    /// The chunk that the stream may yield.
+    type Chunk;
+    /// The error that the stream may yield.
+    type Error;
+
+    /// Attempts to pull out the next chunk, returning `None` once the stream is exhausted.
+    fn poll_next_chunk(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+    ) -> Poll<Option<Result<Self::Chunk, RetryError<Self::Error>>>>;
+}
+
+impl<I, St, M, S, C> Retry<I, ResumableStream<St, M, C>>
+where
+    I: Iterator<Item = Duration>,
+    St: Clone,
+    M: FnMut(St) -> S,
+    S: ChunkStream,
+    C: FnMut(&mut St, S::Chunk),
+{
+    /// Drives the stream made by `make_stream` from `start_state` to its end, handing each
+    /// chunk to `on_chunk` to update the state, e.g. the offset of a resumable download.
+    /// See [`ResumableStream`].
+    ///
+    /// On a transient error the stream is dropped, and after the strategy delay a fresh
+    /// one is made from the updated state, so it resumes after the last chunk. The future
+    /// resolves to the final state, or to the error once the strategy is exhausted or the
+    /// error is permanent.
+    pub fn spawn_resumable_stream<T: IntoIterator<IntoIter = I, Item = Duration>>(
+        strategy: T,
+        start_state: St,
+        make_stream: M,
+        on_chunk: C,
+    ) -> Self {
+        Self::spawn(
+            strategy,
+            ResumableStream::new(start_state, make_stream, on_chunk),
+        )
+    }
+}
+
+/// The state of a resumable stream, shared by the action and its attempts.
+struct Progress<St, C> {
+    state: St,
+    on_chunk: C,
+}
+
+fn lock<St, C>(progress: &Mutex<Progress<St, C>>) -> MutexGuard<'_, Progress<St, C>> {
+    progress.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// An action driving a fresh stream from the current state on each attempt, handing
+/// each chunk to a callback updating the state, created by
+/// [`Retry::spawn_resumable_stream`] function.
+///
+/// An attempt resolves to the state once the stream ends, or to the first error it
+/// yields, keeping the state updated by the chunks before it.
+pub struct ResumableStream<St, M, C> {
+    progress: Arc<Mutex<Progress<St, C>>>,
+    make_stream: M,
+}
+
+impl<St, M, S, C> ResumableStream<St, M, C>
+where
+    St: Clone,
+    M: FnMut(St) -> S,
+    S: ChunkStream,
+    C: FnMut(&mut St, S::Chunk),
+{
+    /// Wraps `make_stream`, called with the current state on each attempt.
+    pub fn new(start_state: St, make_stream: M, on_chunk: C) -> Self {
+        Self {
+            progress: Arc::new(Mutex::new(Progress {
+                state: start_state,
+                on_chunk,
+            })),
+            make_stream,
+        }
+    }
+}
+
+impl<St, M, S, C> Action for ResumableStream<St, M, C>
+where
+    St: Clone,
+    M: FnMut(St) -> S,
+    S: ChunkStream,
+    C: FnMut(&mut St, S::Chunk),
+{
+    type Item = St;
+    type Error = S::Error;
+    type Future = ResumableStreamFuture<S, St, C>;
+
+    fn run(&mut self) -> Self::Future {
+        let state = lock(&self.progress).state.clone();
+        ResumableStreamFuture {
+            stream: (self.make_stream)(state),
+            progress: Arc::clone(&self.progress),
+        }
+    }
+}
+
+/// Future produced by the [`ResumableStream`] action.
+#[pin_project]
+pub struct ResumableStreamFuture<S, St, C> {
+    #[pin]
+    stream: S,
+    progress: Arc<Mutex<Progress<St, C>>>,
+}
+
+impl<S, St, C> Future for ResumableStreamFuture<S, St, C>
+where
+    S: ChunkStream,
+    St: Clone,
+    C: FnMut(&mut St, S::Chunk),
+{
+    type Output = Result<St, RetryError<S::Error>>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            let chunk = match ready!(this.stream.as_mut().poll_next_chunk(cx)) {
+                Some(Ok(chunk)) => chunk,
+                Some(Err(err)) => return Poll::Ready(Err(err)),
+                None => return Poll::Ready(Ok(lock(this.progress).state.clone())),
+            };
+            let mut progress = lock(this.progress);
+            let Progress { state, on_chunk } = &mut *progress;
+            on_chunk(state, chunk);
+            drop(progress);
+        }
+    }
+}
+
+/// Emits the delays of `strategy` as a stream, sleeping each delay before the next one.
+///
+/// This drives an external retry loop, e.g. from stream-processing code, as an
+/// alternative to the built-in [`Retry`] loop.
+///
+/// ```
+/// # #[tokio::main(flavor = "current_thread", start_paused = true)]
+/// # async fn main() {
+/// use std::{pin::pin, time::Duration};
+/// use tokio_retry2::{strategy::FixedInterval, strategy_stream};
+///
+/// let mut delays = pin!(strategy_stream(FixedInterval::from_millis(10).take(2)));
+/// while let Some(delay) = delays.as_mut().next().await {
+///     // retry the operation, `delay` is slept on the next call
+/// #   assert_eq!(delay, Duration::from_millis(10));
+/// }
+/// # }
+/// ```
+pub fn strategy_stream<T: IntoIterator<Item = Duration>>(
+    strategy: T,
+) -> StrategyStream<T::IntoIter> {
+    StrategyStream {
+        strategy: strategy.into_iter(),
+        sleep: None,
+    }
+}
+
+/// Stream of the delays of a strategy, sleeping each delay before yielding the next,
+/// created by [`strategy_stream`] function.
+///
+/// Mirrors `futures::Stream`, without depending on `futures`: a `Stream` is adapted by
+/// forwarding `poll_next` to [`StrategyStream::poll_next`].
+#[pin_project]
+pub struct StrategyStream<I> {
+    strategy: I,
+    #[pin]
+    sleep: Option<Sleep>,
+}
+
+impl<I: Iterator<Item = Duration>> StrategyStream<I> {
+    /// Attempts to pull out the next delay, once the previous one was slept, returning
+    /// `None` once the strategy is exhausted.
+    pub fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Duration>> {
+        let mut this = self.project();
+        if let Some(sleep) = this.sleep.as_mut().as_pin_mut() {
+            ready!(sleep.poll(cx));
+        }
+        let delay = this.strategy.next();
+        this.sleep
+            .set(delay.map(|delay| sleep_until(deadline_after(delay))));
+        Poll::Ready(delay)
+    }
+
+    /// Same as [`StrategyStream::poll_next`], as a future.
+    pub async fn next(mut self: Pin<&mut Self>) -> Option<Duration> {
+        poll_fn(|cx| self.as_mut().poll_next(cx)).await
+    }
+}
@@ -0,0 +1,127 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll, ready},
+    time::SystemTime,
+};
+
+use pin_project::pin_project;
+use tokio::time::{Duration, Sleep, sleep_until};
+
+use crate::{
+    action::Action,
+    future::{Park, Retry, deadline_after},
+};
+
+impl<I, A> Retry<I, A>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    /// Same as [`Retry::spawn`], but only retries while `allowed` returns `true` for the
+    /// current wall-clock time, e.g. outside of a maintenance window.
+    ///
+    /// The predicate is checked once each strategy delay was slept. By default a denied
+    /// retry is paused, checking the predicate again every
+    /// [`RetryIfAllowed::DEFAULT_CHECK_INTERVAL`], see [`RetryIfAllowed::check_interval`];
+    /// with [`RetryIfAllowed::abort_when_denied`] it resolves to the last error instead.
+    /// The first attempt always runs.
+    pub fn spawn_if_allowed<T, F>(strategy: T, action: A, allowed: F) -> RetryIfAllowed<I, A, F>
+    where
+        T: IntoIterator<IntoIter = I, Item = Duration>,
+        F: Fn(SystemTime) -> bool,
+    {
+        RetryIfAllowed::new(strategy.into_iter(), action, allowed)
+    }
+}
+
+/// Future that drives multiple attempts at an action via a retry strategy, retrying only
+/// while a predicate allows it, created by [`Retry::spawn_if_allowed`] function.
+#[pin_project]
+pub struct RetryIfAllowed<I, A, F>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+{
+    #[pin]
+    retry: Retry<I, A>,
+    allowed: F,
+    check_interval: Duration,
+    abort_when_denied: bool,
+    #[pin]
+    check: Option<Sleep>,
+}
+
+impl<I, A, F> RetryIfAllowed<I, A, F>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+    F: Fn(SystemTime) -> bool,
+{
+    /// The default interval between two checks of a denied retry: `1s`.
+    pub const DEFAULT_CHECK_INTERVAL: Duration = Duration::from_secs(1);
+
+    fn new(strategy: I, action: A, allowed: F) -> Self {
+        Self {
+            retry: Retry::spawn(strategy, action).park_at(Park::Attempt),
+            allowed,
+            check_interval: Self::DEFAULT_CHECK_INTERVAL,
+            abort_when_denied: false,
+            check: None,
+        }
+    }
+
+    /// Sets the interval between two checks of the predicate while a retry is denied.
+    #[must_use]
+    pub const fn check_interval(mut self, interval: Duration) -> Self {
+        self.check_interval = interval;
+        self
+    }
+
+    /// Resolves to the last error when a retry is denied, instead of waiting for the
+    /// predicate to allow it.
+    #[must_use]
+    pub const fn abort_when_denied(mut self) -> Self {
+        self.abort_when_denied = true;
+        self
+    }
+}
+
+impl<I, A, F> Future for RetryIfAllowed<I, A, F>
+where
+    I: Iterator<Item = Duration>,
+    A: Action,
+    F: Fn(SystemTime) -> bool,
+{
+    type Output = Result<A::Item, A::Error>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context) -> Poll<Self::Output> {
+        let mut this = self.project();
+        loop {
+            if let Some(check) = this.check.as_mut().as_pin_mut() {
+                ready!(check.poll(cx));
+                this.check.set(None);
+            } else {
+                let poll = this.retry.as_mut().poll(cx);
+                if poll.is_ready() || this.retry.parked().is_none() {
+                    return poll;
+                }
+            }
+            // the loop is parked once each strategy delay was slept
+            if (this.allowed)(SystemTime::now()) {
+                return this.retry.as_mut().unpark(cx);
+            }
+            if *this.abort_when_denied {
+                #[cfg(feature = "tracing")]
+                tracing::warn!("ending retry: retry not allowed");
+                return this
+                    .retry
+                    .as_mut()
+                    .take_last_error()
+                    .map_or(Poll::Pending, |err| Poll::Ready(Err(err)));
+            }
+            this.check
+                .set(Some(sleep_until(deadline_after(*this.check_interval))));
+        }
+    }
+}
@@ -10,7 +10,8 @@ use std::{
 };
 
 use tokio_retry2::{
-    Notify, Retry, RetryError, RetryIf,
+    ChunkStream, FnNotify, NoopNotify, Notify, Retry, RetryAsyncStrategy, RetryDecision,
+    RetryError, RetryIf, RetryOutcome, RetryProgress,
     strategy::{ExponentialBackoff, FixedInterval},
 };
 
@@ -65,6 +66,66 @@ async fn attempts_until_success() {
     assert_eq!(counter.load(Ordering::SeqCst), 4);
 }
 
+#[tokio::test(start_paused = true)]
+async fn no_attempt_nor_sleep_after_success() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let notified = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let cloned_notified = notified.clone();
+    let start = tokio::time::Instant::now();
+    let future = Retry::spawn_notify(
+        FixedInterval::from_millis(100),
+        move || {
+            let previous = cloned_counter.fetch_add(1, Ordering::SeqCst);
+            future::ready(match previous {
+                0 => Err(RetryError::transient(42)),
+                _ => Ok::<(), RetryError<u64>>(()),
+            })
+        },
+        move |_: &u64, _| {
+            cloned_notified.fetch_add(1, Ordering::SeqCst);
+        },
+    );
+
+    assert_eq!(future.await, Ok(()));
+    assert_eq!(start.elapsed(), Duration::from_millis(100));
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+    assert_eq!(notified.load(Ordering::SeqCst), 1);
+
+    // nothing is left running in the background
+    tokio::time::sleep(Duration::from_secs(1)).await;
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn no_sleep_after_final_attempt() {
+    use tokio_retry2::strategy::LinearBackoff;
+
+    let fail = || future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42)));
+    // 4 attempts, sleeping 100ms, 200ms and 300ms between them
+    let between_attempts = Duration::from_millis(600);
+    let strategy = || LinearBackoff::from_millis(100).take(3);
+
+    let start = tokio::time::Instant::now();
+    assert_eq!(Retry::spawn(strategy(), fail).await, Err(42));
+    assert_eq!(start.elapsed(), between_attempts);
+
+    let start = tokio::time::Instant::now();
+    let future = RetryIf::spawn(strategy(), fail, |_: &u64| true, NoopNotify);
+    assert_eq!(future.await, Err(42));
+    assert_eq!(start.elapsed(), between_attempts);
+
+    let start = tokio::time::Instant::now();
+    let future = Retry::spawn_with_overall_timeout(strategy(), fail, Duration::from_secs(10));
+    assert_eq!(future.await, Err(42));
+    assert_eq!(start.elapsed(), between_attempts);
+
+    let start = tokio::time::Instant::now();
+    let future = Retry::spawn_bounded(strategy(), 4, Duration::from_secs(10), fail);
+    assert!(future.await.is_err());
+    assert_eq!(start.elapsed(), between_attempts);
+}
+
 #[tokio::test]
 async fn compatible_with_tokio_core() {
     use tokio_retry2::strategy::FixedInterval;
@@ -245,3 +306,1335 @@ async fn notify_retry_with_custom_struct() {
     assert_eq!(tracked_durations[1], Duration::from_millis(50));
     assert_eq!(tracked_durations[2], Duration::from_millis(100));
 }
+
+#[tokio::test]
+async fn dedup_stops_on_repeated_error() {
+    let s = FixedInterval::from_millis(10).take(10);
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn_dedup(
+        s,
+        move || {
+            cloned_counter.fetch_add(1, Ordering::SeqCst);
+            future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42)))
+        },
+        2,
+    );
+    let res = future.await;
+
+    assert_eq!(res, Err(42));
+    assert_eq!(counter.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn dedup_without_repeats_never_retries() {
+    let s = FixedInterval::from_millis(10).take(5);
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn_dedup(
+        s,
+        move || {
+            cloned_counter.fetch_add(1, Ordering::SeqCst);
+            future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42)))
+        },
+        0,
+    );
+
+    assert_eq!(future.await, Err(42));
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn dedup_keeps_retrying_on_changing_error() {
+    let s = FixedInterval::from_millis(10).take(5);
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn_dedup(
+        s,
+        move || {
+            let previous = cloned_counter.fetch_add(1, Ordering::SeqCst);
+            future::ready(Err::<(), RetryError<usize>>(RetryError::transient(
+                previous,
+            )))
+        },
+        2,
+    );
+    let res = future.await;
+
+    assert_eq!(res, Err(5));
+    assert_eq!(counter.load(Ordering::SeqCst), 6);
+}
+
+#[tokio::test(start_paused = true)]
+async fn overall_timeout_returns_last_error() {
+    let s = FixedInterval::from_millis(100);
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn_with_overall_timeout(
+        s,
+        move || {
+            let previous = cloned_counter.fetch_add(1, Ordering::SeqCst);
+            future::ready(Err::<(), RetryError<usize>>(RetryError::transient(
+                previous + 1,
+            )))
+        },
+        Duration::from_millis(250),
+    );
+    let res = future.await;
+
+    assert_eq!(res, Err(3));
+    assert_eq!(counter.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn overall_timeout_awaits_first_attempt() {
+    let s = FixedInterval::from_millis(100);
+    let future = Retry::spawn_with_overall_timeout(
+        s,
+        || async {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+            Ok::<u64, RetryError<()>>(42)
+        },
+        Duration::from_millis(10),
+    );
+
+    assert_eq!(future.await, Ok(42));
+}
+
+#[tokio::test]
+async fn doesnt_attempt_on_permanent_with_reason() {
+    let retry_strategy = FixedInterval::from_millis(10).take(3);
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn(retry_strategy, move || {
+        cloned_counter.fetch_add(1, Ordering::SeqCst);
+        future::ready(Err::<(), _>(RetryError::permanent_with(42, "not found")))
+    });
+    let err = future.await.unwrap_err();
+
+    assert_eq!(err.reason(), "not found");
+    assert_eq!(err.into_inner(), 42);
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn until_signal_stops_on_shutdown() {
+    let s = FixedInterval::from_millis(100);
+    let future = Retry::spawn_until_signal(
+        s,
+        || future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42))),
+        tokio::time::sleep(Duration::from_millis(150)),
+    );
+
+    assert_eq!(
+        future.await,
+        RetryOutcome::ShutdownRequested { attempts: 2 }
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn until_signal_completes_before_shutdown() {
+    let s = FixedInterval::from_millis(100).take(1);
+    let future = Retry::spawn_until_signal(
+        s,
+        || future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42))),
+        future::pending::<()>(),
+    );
+
+    assert_eq!(future.await, RetryOutcome::Completed(Err(42)));
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum AttemptError {
+    TimedOut,
+    Custom(u64),
+}
+
+impl From<tokio::time::error::Elapsed> for AttemptError {
+    fn from(_: tokio::time::error::Elapsed) -> Self {
+        Self::TimedOut
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn attempt_timeout_retries_hanging_attempt() {
+    let s = FixedInterval::from_millis(10).take(3);
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn_with_attempt_timeout(
+        s,
+        move || {
+            let previous = cloned_counter.fetch_add(1, Ordering::SeqCst);
+            async move {
+                if previous == 0 {
+                    tokio::time::sleep(Duration::from_secs(10)).await;
+                }
+                Ok::<usize, RetryError<AttemptError>>(previous)
+            }
+        },
+        Duration::from_millis(100),
+    );
+
+    assert_eq!(future.await, Ok(1));
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn attempt_timeout_with_custom_error() {
+    let s = FixedInterval::from_millis(10).take(1);
+    let future = Retry::spawn_with_attempt_timeout_err(
+        s,
+        || async {
+            tokio::time::sleep(Duration::from_secs(10)).await;
+            Ok::<(), RetryError<AttemptError>>(())
+        },
+        Duration::from_millis(100),
+        |_| AttemptError::Custom(42),
+    );
+
+    assert_eq!(future.await, Err(AttemptError::Custom(42)));
+}
+
+#[tokio::test(start_paused = true)]
+async fn timed_reports_elapsed_on_success() {
+    let s = FixedInterval::from_millis(100);
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn_timed(s, move || {
+        let previous = cloned_counter.fetch_add(1, Ordering::SeqCst);
+        if previous < 2 {
+            future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42)))
+        } else {
+            future::ready(Ok::<(), RetryError<u64>>(()))
+        }
+    });
+
+    assert_eq!(future.await, Ok(((), Duration::from_millis(200))));
+}
+
+#[tokio::test(start_paused = true)]
+async fn timed_reports_elapsed_on_failure() {
+    let s = FixedInterval::from_millis(100).take(3);
+    let future = Retry::spawn_timed(s, || {
+        future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42)))
+    });
+
+    assert_eq!(future.await, Err((42, Duration::from_millis(300))));
+}
+
+#[tokio::test(start_paused = true)]
+async fn retry_after_replaces_strategy_delay() {
+    let start = tokio::time::Instant::now();
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn(FixedInterval::from_millis(100), move || {
+        let previous = cloned_counter.fetch_add(1, Ordering::SeqCst);
+        future::ready(match previous {
+            0 => Err(RetryError::retry_after(42, Duration::from_millis(5))),
+            1 => Err(RetryError::retry_after(42, Duration::from_millis(300))),
+            _ => Ok::<(), RetryError<u64>>(()),
+        })
+    });
+
+    assert_eq!(future.await, Ok(()));
+    assert_eq!(start.elapsed(), Duration::from_millis(305));
+}
+
+type Offsets = Arc<std::sync::Mutex<Vec<Duration>>>;
+
+fn retry_recording_offsets(
+    advance: bool,
+) -> (impl std::future::Future<Output = Result<(), u64>>, Offsets) {
+    let start = tokio::time::Instant::now();
+    let offsets = Offsets::default();
+    let cloned_offsets = offsets.clone();
+    let s = tokio_retry2::strategy::LinearBackoff::from_millis(100);
+    let future = Retry::spawn(s, move || {
+        let attempt = {
+            let mut offsets = cloned_offsets.lock().unwrap();
+            offsets.push(start.elapsed());
+            offsets.len()
+        };
+        future::ready(match attempt {
+            1 => Err(RetryError::retry_after(42, Duration::from_millis(5))),
+            2 => Err(RetryError::transient(42)),
+            _ => Ok(()),
+        })
+    })
+    .advance_on_retry_after(advance);
+    (future, offsets)
+}
+
+#[tokio::test(start_paused = true)]
+async fn retry_after_advances_strategy_by_default() {
+    let (future, offsets) = retry_recording_offsets(true);
+    assert_eq!(future.await, Ok(()));
+    assert_eq!(
+        *offsets.lock().unwrap(),
+        [0, 5, 205].map(Duration::from_millis)
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn retry_after_without_advancing_strategy() {
+    let (future, offsets) = retry_recording_offsets(false);
+    assert_eq!(future.await, Ok(()));
+    assert_eq!(
+        *offsets.lock().unwrap(),
+        [0, 5, 105].map(Duration::from_millis)
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn retry_after_without_advancing_strategy_is_capped() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let s = FixedInterval::from_millis(100).take(1);
+    let future = Retry::spawn(s, move || {
+        cloned_counter.fetch_add(1, Ordering::SeqCst);
+        future::ready(Err::<(), _>(RetryError::retry_after(42, Duration::ZERO)))
+    })
+    .safety_cap(Some(3))
+    .advance_on_retry_after(false);
+
+    assert_eq!(future.await, Err(42));
+    assert_eq!(counter.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn retry_after_advances_strategy_without_safety_cap() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let s = FixedInterval::from_millis(100).take(1);
+    let future = Retry::spawn(s, move || {
+        cloned_counter.fetch_add(1, Ordering::SeqCst);
+        future::ready(Err::<(), _>(RetryError::retry_after(42, Duration::ZERO)))
+    })
+    .advance_on_retry_after(false)
+    .safety_cap(None);
+
+    assert_eq!(future.await, Err(42));
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn notify_with_noop_notify() {
+    let s = FixedInterval::from_millis(10).take(2);
+    let future = Retry::spawn_notify(
+        s,
+        || future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42))),
+        NoopNotify,
+    );
+
+    assert_eq!(future.await, Err(42));
+}
+
+#[tokio::test]
+async fn notify_with_boxed_fn_notify() {
+    let s = FixedInterval::from_millis(10).take(2);
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let notify: Box<dyn Notify<u64>> = Box::new(FnNotify::new(move |err: &u64, _| {
+        assert_eq!(*err, 42);
+        cloned_counter.fetch_add(1, Ordering::SeqCst);
+    }));
+    let future = Retry::spawn_notify(
+        s,
+        || future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42))),
+        notify,
+    );
+
+    assert_eq!(future.await, Err(42));
+    assert_eq!(counter.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn collect_errors_in_order() {
+    let s = FixedInterval::from_millis(10).take(2);
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn_collect_errors(
+        s,
+        move || {
+            let previous = cloned_counter.fetch_add(1, Ordering::SeqCst);
+            future::ready(Err::<(), RetryError<usize>>(RetryError::transient(
+                previous + 1,
+            )))
+        },
+        10,
+    );
+
+    assert_eq!(future.await, Err(vec![1, 2, 3]));
+}
+
+#[tokio::test]
+async fn collect_errors_keeps_most_recent() {
+    let s = FixedInterval::from_millis(10).take(4);
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn_collect_errors(
+        s,
+        move || {
+            let previous = cloned_counter.fetch_add(1, Ordering::SeqCst);
+            future::ready(Err::<(), RetryError<usize>>(RetryError::transient(
+                previous + 1,
+            )))
+        },
+        2,
+    );
+
+    assert_eq!(future.await, Err(vec![4, 5]));
+}
+
+#[tokio::test]
+async fn collect_errors_keeps_final_error_and_zero_delays() {
+    let failing = |counter: Arc<AtomicUsize>| {
+        move || {
+            let previous = counter.fetch_add(1, Ordering::SeqCst);
+            future::ready(Err::<(), RetryError<usize>>(RetryError::transient(
+                previous + 1,
+            )))
+        }
+    };
+
+    let s = FixedInterval::from_millis(10).take(2);
+    let future = Retry::spawn_collect_errors(s, failing(Arc::default()), 0);
+    assert_eq!(future.await, Err(vec![3]));
+
+    let s = FixedInterval::from_millis(0).take(3);
+    let future = Retry::spawn_collect_errors(s, failing(Arc::default()), 10);
+    assert_eq!(future.await, Err(vec![1, 2, 3, 4]));
+}
+
+#[tokio::test]
+async fn resumable_restarts_with_new_strategy() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn_resumable(
+        FixedInterval::from_millis(10).take(0),
+        move || {
+            let previous = cloned_counter.fetch_add(1, Ordering::SeqCst);
+            future::ready(match previous {
+                0 => Err(RetryError::permanent("not yet")),
+                1 => Err(RetryError::transient("almost")),
+                _ => Ok(previous),
+            })
+        },
+        |_: &&str| Some(FixedInterval::from_millis(10).take(2)),
+    );
+
+    assert_eq!(future.await, Ok(2));
+    assert_eq!(counter.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn resumable_fails_without_new_strategy() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn_resumable(
+        FixedInterval::from_millis(10).take(2),
+        move || {
+            cloned_counter.fetch_add(1, Ordering::SeqCst);
+            future::ready(Err::<(), _>(RetryError::permanent(42)))
+        },
+        |_: &u64| None,
+    );
+
+    assert_eq!(future.await, Err(42));
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn catch_unwind_retries_panicking_attempt() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn_catch_unwind(
+        FixedInterval::from_millis(10).take(2),
+        move || {
+            let previous = cloned_counter.fetch_add(1, Ordering::SeqCst);
+            assert!(previous > 0, "first attempt panics");
+            future::ready(Ok::<usize, RetryError<&str>>(previous))
+        },
+        |_| RetryError::transient("panicked"),
+    );
+
+    assert_eq!(future.await, Ok(1));
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn catch_unwind_aborts_on_permanent_conversion() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn_catch_unwind(
+        FixedInterval::from_millis(10).take(2),
+        move || {
+            cloned_counter.fetch_add(1, Ordering::SeqCst);
+            async { unreachable!("panics while polling") }
+        },
+        |_| RetryError::permanent("panicked"),
+    );
+
+    assert_eq!(future.await, Err::<(), _>("panicked"));
+    assert_eq!(counter.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn spawn_on_drives_sleeps_with_handle_timer() {
+    let timer = tokio::runtime::Builder::new_multi_thread()
+        .worker_threads(1)
+        .enable_time()
+        .build()
+        .unwrap();
+    // no timer driver, sleeping on the ambient runtime would panic
+    let runtime = tokio::runtime::Builder::new_current_thread()
+        .build()
+        .unwrap();
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn_on(
+        timer.handle(),
+        FixedInterval::from_millis(10).take(2),
+        move || {
+            let previous = cloned_counter.fetch_add(1, Ordering::SeqCst);
+            future::ready(if previous < 2 {
+                Err(RetryError::transient(previous))
+            } else {
+                Ok(previous)
+            })
+        },
+    );
+
+    assert_eq!(runtime.block_on(future), Ok(2));
+    assert_eq!(counter.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn retry_at_sleeps_until_absolute_instant() {
+    let start = tokio::time::Instant::now();
+    let when = start + Duration::from_millis(100);
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn(FixedInterval::from_millis(10).take(1), move || {
+        let previous = cloned_counter.fetch_add(1, Ordering::SeqCst);
+        async move {
+            if previous == 0 {
+                // time spent in the attempt doesn't delay the absolute retry
+                tokio::time::sleep(Duration::from_millis(30)).await;
+                return Err(RetryError::retry_at((), when));
+            }
+            Ok(tokio::time::Instant::now())
+        }
+    });
+
+    assert_eq!(future.await, Ok(when));
+}
+
+#[tokio::test(start_paused = true)]
+async fn retry_at_in_the_past_retries_immediately() {
+    let start = tokio::time::Instant::now();
+    tokio::time::advance(Duration::from_secs(1)).await;
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn(FixedInterval::from_secs(10).take(2), move || {
+        cloned_counter.fetch_add(1, Ordering::SeqCst);
+        future::ready(Err::<(), _>(RetryError::retry_at((), start)))
+    });
+    let retried_at = tokio::time::Instant::now();
+
+    assert_eq!(future.await, Err(()));
+    assert_eq!(counter.load(Ordering::SeqCst), 3);
+    assert_eq!(retried_at.elapsed(), Duration::ZERO);
+}
+
+#[tokio::test(start_paused = true)]
+async fn unrepresentable_deadlines_do_not_panic() {
+    let future = Retry::spawn_with_overall_timeout(
+        FixedInterval::from_millis(10).take(1),
+        || future::ready(Ok::<u64, RetryError<()>>(42)),
+        Duration::MAX,
+    );
+    assert_eq!(future.await, Ok(42));
+
+    let future = Retry::spawn(FixedInterval::from_millis(10).take(1), {
+        let mut attempts = 0;
+        move || {
+            attempts += 1;
+            future::ready(match attempts {
+                1 => Err(RetryError::retry_after((), Duration::MAX)),
+                _ => Ok::<u64, RetryError<()>>(42),
+            })
+        }
+    });
+    let polled = tokio::time::timeout(Duration::from_millis(10), future).await;
+    assert!(polled.is_err());
+}
+
+#[tokio::test(start_paused = true)]
+async fn cumulative_delay_saturates() {
+    let durations = Arc::new(std::sync::Mutex::new(Vec::new()));
+    let recorded = durations.clone();
+    let future = Retry::spawn_notify(
+        std::iter::repeat_n(Duration::MAX, 2),
+        || future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42))),
+        move |_: &u64, duration| recorded.lock().unwrap().push(duration),
+    );
+
+    assert_eq!(future.await, Err(42));
+    assert_eq!(
+        *durations.lock().unwrap(),
+        [Duration::ZERO, Duration::MAX, Duration::MAX]
+    );
+}
+
+#[tokio::test]
+async fn zero_delay_yields_to_other_tasks() {
+    let ready = Arc::new(AtomicUsize::new(0));
+    let cloned_ready = ready.clone();
+    tokio::spawn(async move { cloned_ready.store(1, Ordering::SeqCst) });
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn(FixedInterval::new(Duration::ZERO), move || {
+        cloned_counter.fetch_add(1, Ordering::SeqCst);
+        future::ready(match ready.load(Ordering::SeqCst) {
+            0 => Err(RetryError::transient(())),
+            _ => Ok(()),
+        })
+    });
+
+    assert_eq!(future.await, Ok(()));
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+}
+
+#[tokio::test]
+async fn progress_is_sent_on_each_failure() {
+    let (tx, mut rx) = tokio::sync::mpsc::channel(8);
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn_with_progress(
+        ExponentialBackoff::from_millis(2).take(3),
+        move || {
+            let previous = cloned_counter.fetch_add(1, Ordering::SeqCst);
+            future::ready(if previous < 3 {
+                Err(RetryError::transient(previous))
+            } else {
+                Ok(previous)
+            })
+        },
+        tx,
+    );
+
+    assert_eq!(future.await, Ok(3));
+    for (attempt, delay) in [(1, 2), (2, 4), (3, 8)] {
+        assert_eq!(
+            rx.recv().await,
+            Some(RetryProgress {
+                attempt,
+                last_error_debug: (attempt - 1).to_string(),
+                next_delay: Duration::from_millis(delay),
+            })
+        );
+    }
+    assert_eq!(rx.recv().await, None);
+}
+
+#[tokio::test]
+async fn exhausted_strategy_stops_even_if_condition_wants_to_retry() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = RetryIf::spawn(
+        FixedInterval::from_millis(10).take(1),
+        move || {
+            let previous = cloned_counter.fetch_add(1, Ordering::SeqCst);
+            future::ready(Err::<(), RetryError<usize>>(RetryError::transient(
+                previous + 1,
+            )))
+        },
+        |_: &usize| true,
+        NoopNotify,
+    );
+
+    assert_eq!(future.await, Err(2));
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+}
+
+async fn fails_below(limit: usize) -> Result<usize, RetryError<usize>> {
+    static CALLS: AtomicUsize = AtomicUsize::new(0);
+    let calls = CALLS.fetch_add(1, Ordering::SeqCst) + 1;
+    if calls < limit {
+        return Err(RetryError::transient(calls));
+    }
+    Ok(calls)
+}
+
+#[tokio::test]
+async fn spawn_fn_calls_free_async_fn() {
+    let future = Retry::spawn_fn(FixedInterval::from_millis(10).take(3), fails_below, 3);
+
+    assert_eq!(future.await, Ok(3));
+}
+
+#[tokio::test]
+async fn chained_notify_forwards_to_all_handlers() {
+    let logged = Arc::new(AtomicUsize::new(0));
+    let recorded = Arc::new(AtomicUsize::new(0));
+    let cloned_logged = logged.clone();
+    let cloned_recorded = recorded.clone();
+    let notify = FnNotify::new(move |_: &u64, _| {
+        cloned_logged.fetch_add(1, Ordering::SeqCst);
+    })
+    .and_then(move |_: &u64, _| {
+        cloned_recorded.fetch_add(1, Ordering::SeqCst);
+    });
+    let future = Retry::spawn_notify(
+        FixedInterval::from_millis(10).take(2),
+        || future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42))),
+        notify,
+    );
+
+    assert_eq!(future.await, Err(42));
+    assert_eq!(logged.load(Ordering::SeqCst), 3);
+    assert_eq!(recorded.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn async_strategy_is_awaited_for_each_delay() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let fetched = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let cloned_fetched = fetched.clone();
+    let start = tokio::time::Instant::now();
+    let future = Retry::spawn_with_async_strategy(
+        move || {
+            let fetched = cloned_fetched.fetch_add(1, Ordering::SeqCst);
+            async move {
+                tokio::task::yield_now().await;
+                [Duration::from_millis(100), Duration::from_millis(200)]
+                    .get(fetched)
+                    .copied()
+            }
+        },
+        move || {
+            cloned_counter.fetch_add(1, Ordering::SeqCst);
+            future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42)))
+        },
+    );
+
+    assert_eq!(future.await, Err(42));
+    assert_eq!(counter.load(Ordering::SeqCst), 3);
+    assert_eq!(fetched.load(Ordering::SeqCst), 3);
+    assert_eq!(start.elapsed(), Duration::from_millis(300));
+}
+
+#[tokio::test(start_paused = true)]
+async fn async_strategy_delay_is_replaced_by_retry_after() {
+    let fetched = Arc::new(AtomicUsize::new(0));
+    let cloned_fetched = fetched.clone();
+    let mut attempts = 0;
+    let start = tokio::time::Instant::now();
+    let future = RetryAsyncStrategy::spawn(
+        move || {
+            cloned_fetched.fetch_add(1, Ordering::SeqCst);
+            future::ready(Some(Duration::from_millis(100)))
+        },
+        move || {
+            attempts += 1;
+            future::ready(match attempts {
+                1 => Err(RetryError::retry_after(attempts, Duration::from_millis(10))),
+                _ => Ok::<u64, RetryError<u64>>(attempts),
+            })
+        },
+    );
+
+    assert_eq!(future.await, Ok(2));
+    assert_eq!(fetched.load(Ordering::SeqCst), 1);
+    assert_eq!(start.elapsed(), Duration::from_millis(10));
+}
+
+#[tokio::test]
+async fn stateful_context_survives_transient_failures() {
+    let future = Retry::spawn_stateful(
+        0_u64,
+        FixedInterval::from_millis(10).take(5),
+        |attempts: &mut u64| {
+            *attempts += 1;
+            future::ready(if *attempts < 3 {
+                Err(RetryError::transient(*attempts))
+            } else {
+                Ok::<u64, RetryError<u64>>(*attempts)
+            })
+        },
+    );
+
+    assert_eq!(future.await, Ok(3));
+}
+
+#[tokio::test]
+async fn spawn_blocking_runs_attempts_off_the_async_thread() {
+    let async_thread = std::thread::current().id();
+    let mut attempts = 0;
+    let future = Retry::spawn_blocking(FixedInterval::from_millis(1).take(5), move || {
+        assert_ne!(std::thread::current().id(), async_thread);
+        attempts += 1;
+        if attempts < 3 {
+            Err(RetryError::transient(AttemptError::Custom(attempts)))
+        } else {
+            Ok::<u64, RetryError<AttemptError>>(attempts)
+        }
+    });
+
+    assert_eq!(future.await, Ok(3));
+}
+
+#[tokio::test]
+async fn spawn_blocking_runs_inline_once_its_runtime_shut_down() {
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    let handle = runtime.handle().clone();
+    runtime.shutdown_background();
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let cloned_attempts = attempts.clone();
+    let future = {
+        let _guard = handle.enter();
+        Retry::spawn_blocking(FixedInterval::from_millis(1).take(5), move || {
+            cloned_attempts.fetch_add(1, Ordering::SeqCst);
+            Ok::<(), RetryError<AttemptError>>(())
+        })
+    };
+
+    assert_eq!(future.await, Ok(()));
+    assert_eq!(attempts.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test(start_paused = true)]
+async fn spawn_returning_hands_back_the_strategy() {
+    let start = tokio::time::Instant::now();
+    let mut attempts = 0;
+    let future = Retry::spawn_returning(ExponentialBackoff::from_millis(10), move || {
+        attempts += 1;
+        future::ready(if attempts < 3 {
+            Err(RetryError::transient(attempts))
+        } else {
+            Ok::<u64, RetryError<u64>>(attempts)
+        })
+    });
+
+    let (result, mut strategy) = future.await;
+    assert_eq!(result, Ok(3));
+    assert_eq!(start.elapsed(), Duration::from_millis(110));
+    assert_eq!(strategy.next(), Some(Duration::from_secs(1)));
+}
+
+#[tokio::test]
+async fn safety_cap_ends_unbounded_strategy() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn(std::iter::repeat(Duration::ZERO), move || {
+        cloned_counter.fetch_add(1, Ordering::SeqCst);
+        future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42)))
+    })
+    .with_safety_cap();
+
+    assert_eq!(future.await, Err(42));
+    assert_eq!(counter.load(Ordering::SeqCst), 10_000);
+}
+
+#[tokio::test]
+async fn safety_cap_is_configurable() {
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn(std::iter::repeat(Duration::ZERO), move || {
+        let attempt = cloned_counter.fetch_add(1, Ordering::SeqCst) + 1;
+        future::ready(if attempt < 20_000 {
+            Err(RetryError::transient(attempt))
+        } else {
+            Ok::<usize, RetryError<usize>>(attempt)
+        })
+    });
+
+    // no cap by default
+    assert_eq!(future.await, Ok(20_000));
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn(std::iter::repeat(Duration::ZERO), move || {
+        cloned_counter.fetch_add(1, Ordering::SeqCst);
+        future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42)))
+    });
+
+    assert_eq!(future.safety_cap(Some(3)).await, Err(42));
+    assert_eq!(counter.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn free_functions_match_associated_functions() {
+    let action = || {
+        let mut attempts = 0;
+        move || {
+            attempts += 1;
+            future::ready(if attempts < 3 {
+                Err(RetryError::transient(attempts))
+            } else {
+                Ok::<u64, RetryError<u64>>(attempts)
+            })
+        }
+    };
+    let strategy = || FixedInterval::from_millis(10).take(5);
+
+    assert_eq!(
+        tokio_retry2::retry(strategy(), action()).await,
+        Retry::spawn(strategy(), action()).await
+    );
+
+    let condition = |err: &u64| *err < 2;
+    assert_eq!(
+        tokio_retry2::retry_if(strategy(), action(), condition).await,
+        RetryIf::spawn(strategy(), action(), condition, NoopNotify).await
+    );
+    assert_eq!(
+        tokio_retry2::retry_if(strategy(), action(), condition).await,
+        Err(2)
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn detailed_failure_surfaces_last_retry_after() {
+    let mut attempts = 0;
+    let future = Retry::spawn_detailed(FixedInterval::from_millis(10).take(2), move || {
+        attempts += 1;
+        future::ready(Err::<(), RetryError<u64>>(match attempts {
+            1 => RetryError::retry_after(attempts, Duration::from_secs(1)),
+            2 => RetryError::transient(attempts),
+            _ => RetryError::retry_after(attempts, Duration::from_secs(5)),
+        }))
+    });
+
+    assert_eq!(
+        future.await,
+        Err(tokio_retry2::RetryFailure {
+            error: 3,
+            attempts: 3,
+            last_retry_after: Some(Duration::from_secs(5)),
+        })
+    );
+
+    let future = Retry::spawn_detailed(FixedInterval::from_millis(10).take(1), || {
+        future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42)))
+    });
+    assert_eq!(future.await.unwrap_err().last_retry_after, None);
+}
+
+#[tokio::test(start_paused = true)]
+async fn strategy_stream_sleeps_between_delays() {
+    let start = tokio::time::Instant::now();
+    let mut delays = std::pin::pin!(tokio_retry2::strategy_stream(
+        ExponentialBackoff::from_millis(10).take(3)
+    ));
+
+    let mut yielded = Vec::new();
+    while let Some(delay) = delays.as_mut().next().await {
+        yielded.push((delay, start.elapsed()));
+    }
+
+    assert_eq!(
+        yielded,
+        [
+            (Duration::from_millis(10), Duration::ZERO),
+            (Duration::from_millis(100), Duration::from_millis(10)),
+            (Duration::from_secs(1), Duration::from_millis(110)),
+        ]
+    );
+    assert_eq!(start.elapsed(), Duration::from_millis(1110));
+}
+
+/// Yields the chunks from an offset, failing after `fail_after` chunks if set.
+struct Chunks {
+    data: &'static [&'static str],
+    offset: usize,
+    fail_after: Option<usize>,
+}
+
+impl ChunkStream for Chunks {
+    type Chunk = &'static str;
+    type Error = &'static str;
+
+    fn poll_next_chunk(
+        self: std::pin::Pin<&mut Self>,
+        _: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<Option<Result<Self::Chunk, RetryError<Self::Error>>>> {
+        let this = self.get_mut();
+        if this.fail_after == Some(0) {
+            this.fail_after = None;
+            return std::task::Poll::Ready(Some(Err(RetryError::transient("reset"))));
+        }
+        this.fail_after = this.fail_after.map(|n| n - 1);
+        let chunk = this.data.get(this.offset).copied();
+        this.offset += 1;
+        std::task::Poll::Ready(chunk.map(Ok))
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn resumable_stream_resumes_from_last_chunk() {
+    let start = tokio::time::Instant::now();
+    let mut streams = 0;
+    let future = Retry::spawn_resumable_stream(
+        FixedInterval::from_millis(100).take(3),
+        (0, String::new()),
+        |(offset, _)| {
+            streams += 1;
+            Chunks {
+                data: &["ab", "cd"],
+                offset,
+                fail_after: (streams == 1).then_some(1),
+            }
+        },
+        |(offset, body), chunk| {
+            *offset += 1;
+            body.push_str(chunk);
+        },
+    );
+
+    assert_eq!(future.await, Ok((2, "abcd".to_owned())));
+    assert_eq!(start.elapsed(), Duration::from_millis(100));
+    assert_eq!(streams, 2);
+}
+
+#[tokio::test]
+async fn resumable_stream_gives_up_when_strategy_is_exhausted() {
+    let future = Retry::spawn_resumable_stream(
+        std::iter::empty(),
+        0,
+        |offset| Chunks {
+            data: &["ab", "cd"],
+            offset,
+            fail_after: Some(1),
+        },
+        |offset, _| *offset += 1,
+    );
+
+    assert_eq!(future.await, Err("reset"));
+}
+
+#[tokio::test(start_paused = true)]
+async fn decide_condition_controls_each_retry() {
+    let start = tokio::time::Instant::now();
+    let mut attempts = 0;
+    let future = RetryIf::spawn_decide(
+        FixedInterval::from_millis(100).take(5),
+        move || {
+            attempts += 1;
+            future::ready(Err::<(), RetryError<u64>>(RetryError::transient(attempts)))
+        },
+        |err: &u64| match err {
+            1 => RetryDecision::RetryNow,
+            2 => RetryDecision::Retry,
+            _ => RetryDecision::Stop,
+        },
+    );
+
+    assert_eq!(future.await, Err(3));
+    assert_eq!(start.elapsed(), Duration::from_millis(100));
+}
+
+#[tokio::test]
+async fn notify_reports_actual_slept_duration() {
+    #[derive(Default)]
+    struct Slept(Arc<std::sync::Mutex<Vec<(Duration, Duration)>>>);
+
+    impl Notify<u64> for Slept {
+        fn notify(&mut self, _err: &u64, _duration: Duration) {}
+
+        fn slept(&mut self, requested: Duration, actual: Duration) {
+            self.0.lock().unwrap().push((requested, actual));
+        }
+    }
+
+    let slept = Slept::default();
+    let recorded = slept.0.clone();
+    let future = Retry::spawn_notify(
+        FixedInterval::from_millis(20).take(2),
+        || future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42))),
+        slept,
+    );
+
+    assert_eq!(future.await, Err(42));
+    let recorded = recorded.lock().unwrap();
+    assert_eq!(recorded.len(), 2);
+    for (requested, actual) in recorded.iter() {
+        assert_eq!(*requested, Duration::from_millis(20));
+        assert!(actual >= requested);
+        assert!(*actual < Duration::from_secs(1));
+    }
+}
+
+#[tokio::test(start_paused = true)]
+async fn notify_reports_success_after_retries() {
+    #[derive(Default)]
+    struct Succeeded(Arc<std::sync::Mutex<Vec<(usize, Duration)>>>);
+
+    impl Notify<u64> for Succeeded {
+        fn notify(&mut self, _err: &u64, _duration: Duration) {}
+
+        fn succeeded(&mut self, attempts: usize, elapsed: Duration) {
+            self.0.lock().unwrap().push((attempts, elapsed));
+        }
+    }
+
+    let succeeded = Succeeded::default();
+    let recorded = succeeded.0.clone();
+    let mut attempts = 0;
+    let future = Retry::spawn_notify(
+        FixedInterval::from_millis(100).take(5),
+        move || {
+            attempts += 1;
+            future::ready(if attempts < 3 {
+                Err(RetryError::transient(attempts))
+            } else {
+                Ok(attempts)
+            })
+        },
+        succeeded,
+    );
+
+    assert_eq!(future.await, Ok(3));
+    assert_eq!(*recorded.lock().unwrap(), [(3, Duration::from_millis(200))]);
+}
+
+#[tokio::test]
+async fn boxed_retry_can_be_stored_and_awaited_later() {
+    struct Pending {
+        retry: tokio_retry2::BoxedRetry<u64, u64>,
+    }
+
+    fn assert_unpin_send<T: Unpin + Send>(_: &T) {}
+
+    let mut attempts = 0;
+    let pending = Pending {
+        retry: Retry::spawn_boxed(FixedInterval::from_millis(10).take(3), move || {
+            attempts += 1;
+            future::ready(match attempts {
+                1 => Err(RetryError::transient(attempts)),
+                _ => Ok(attempts),
+            })
+        }),
+    };
+    assert_unpin_send(&pending.retry);
+
+    assert_eq!(pending.retry.await, Ok(2));
+}
+
+#[tokio::test]
+async fn concurrent_retries_share_a_budget() {
+    let budget = Arc::new(tokio_retry2::RetryBudget::new(3));
+    let attempts = Arc::new(AtomicUsize::new(0));
+    let spawn = |budget, attempts: Arc<AtomicUsize>| {
+        Retry::spawn_with_budget(
+            FixedInterval::from_millis(10).take(10),
+            move || {
+                attempts.fetch_add(1, Ordering::SeqCst);
+                future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42)))
+            },
+            budget,
+        )
+    };
+
+    let (first, second) = tokio::join!(
+        spawn(budget.clone(), attempts.clone()),
+        spawn(budget.clone(), attempts.clone())
+    );
+
+    assert_eq!((first, second), (Err(42), Err(42)));
+    // 2 first attempts, and 3 retries from the shared budget
+    assert_eq!(attempts.load(Ordering::SeqCst), 5);
+    assert_eq!(budget.remaining(), 0);
+}
+
+#[tokio::test]
+async fn exhausted_strategy_keeps_the_budget() {
+    let budget = Arc::new(tokio_retry2::RetryBudget::new(3));
+    let result = Retry::spawn_with_budget(
+        FixedInterval::from_millis(1).take(1),
+        || future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42))),
+        budget.clone(),
+    )
+    .await;
+
+    assert_eq!(result, Err(42));
+    // only the single scheduled retry withdrew
+    assert_eq!(budget.remaining(), 2);
+}
+
+#[tokio::test(start_paused = true)]
+async fn spawn_bounded_stops_at_first_limit() {
+    use tokio_retry2::StopReason;
+
+    let fail = || future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42)));
+
+    let start = tokio::time::Instant::now();
+    let future = Retry::spawn_bounded(
+        FixedInterval::from_millis(100),
+        3,
+        Duration::from_secs(10),
+        fail,
+    );
+    assert_eq!(future.await, Err((42, StopReason::MaxAttempts)));
+    assert_eq!(start.elapsed(), Duration::from_millis(200));
+
+    let start = tokio::time::Instant::now();
+    let future = Retry::spawn_bounded(
+        FixedInterval::from_millis(100),
+        10,
+        Duration::from_millis(250),
+        fail,
+    );
+    assert_eq!(future.await, Err((42, StopReason::MaxElapsed)));
+    assert_eq!(start.elapsed(), Duration::from_millis(250));
+
+    let future = Retry::spawn_bounded(
+        FixedInterval::from_millis(100).take(1),
+        10,
+        Duration::from_secs(10),
+        fail,
+    );
+    assert_eq!(future.await, Err((42, StopReason::StrategyExhausted)));
+
+    let future = Retry::spawn_bounded(
+        FixedInterval::from_millis(100),
+        10,
+        Duration::from_secs(10),
+        || future::ready(Err::<(), RetryError<u64>>(RetryError::permanent(42))),
+    );
+    assert_eq!(future.await, Err((42, StopReason::Permanent)));
+}
+
+#[tokio::test]
+async fn spawn_bounded_yields_on_zero_delays() {
+    let ready = Arc::new(AtomicUsize::new(0));
+    let cloned_ready = ready.clone();
+    tokio::spawn(async move { cloned_ready.store(1, Ordering::SeqCst) });
+
+    let future = Retry::spawn_bounded(
+        FixedInterval::new(Duration::ZERO),
+        usize::MAX,
+        Duration::from_secs(10),
+        move || {
+            future::ready(match ready.load(Ordering::SeqCst) {
+                0 => Err(RetryError::transient(())),
+                _ => Ok(()),
+            })
+        },
+    );
+
+    assert_eq!(future.await, Ok(()));
+}
+
+#[tokio::test(start_paused = true)]
+async fn spawn_bounded_succeeds_before_limits() {
+    let mut attempts = 0;
+    let future = Retry::spawn_bounded(
+        FixedInterval::from_millis(100),
+        3,
+        Duration::from_secs(1),
+        move || {
+            attempts += 1;
+            future::ready(match attempts {
+                1 => Err(RetryError::transient(attempts)),
+                2 => Err(RetryError::retry_after(attempts, Duration::from_millis(10))),
+                _ => Ok::<u64, RetryError<u64>>(attempts),
+            })
+        },
+    );
+
+    assert_eq!(future.await, Ok(3));
+}
+
+#[tokio::test]
+async fn first_retry_hook_fires_once() {
+    let fired = Arc::new(AtomicUsize::new(0));
+    let cloned_fired = fired.clone();
+    let future = Retry::spawn_on_first_retry(
+        FixedInterval::from_millis(1).take(3),
+        || future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42))),
+        move || {
+            cloned_fired.fetch_add(1, Ordering::SeqCst);
+        },
+    );
+    assert_eq!(future.await, Err(42));
+    assert_eq!(fired.load(Ordering::SeqCst), 1);
+
+    let cloned_fired = fired.clone();
+    let future = Retry::spawn_on_first_retry(
+        FixedInterval::from_millis(1).take(3),
+        || future::ready(Ok::<(), RetryError<u64>>(())),
+        move || {
+            cloned_fired.fetch_add(1, Ordering::SeqCst);
+        },
+    );
+    assert_eq!(future.await, Ok(()));
+    assert_eq!(fired.load(Ordering::SeqCst), 1);
+}
+
+#[test]
+fn error_built_and_inspected_through_public_api() {
+    let describe = |err: &RetryError<u64>| match err {
+        RetryError::Permanent(_) => "permanent",
+        RetryError::Transient { .. } => "transient",
+        _ => "other",
+    };
+
+    let err = RetryError::retry_after(1, Duration::from_millis(10));
+    assert_eq!(describe(&err), "transient");
+    assert!(err.is_transient());
+    assert_eq!(err.get_retry_after(), Some(Duration::from_millis(10)));
+    assert_eq!(err.get_retry_at(), None);
+    assert_eq!(*err.inner(), 1);
+
+    let retry_at = tokio::time::Instant::now();
+    let err = RetryError::retry_at(2, retry_at);
+    assert_eq!(describe(&err), "other");
+    assert!(err.is_transient());
+    assert_eq!(err.get_retry_after(), None);
+    assert_eq!(err.get_retry_at(), Some(retry_at));
+
+    let err = RetryError::permanent(3);
+    assert_eq!(describe(&err), "permanent");
+    assert!(err.is_permanent());
+    assert_eq!(err.into_inner(), 3);
+}
+
+#[tokio::test(start_paused = true)]
+async fn spawn_if_allowed_waits_or_aborts_when_denied() {
+    let start = tokio::time::Instant::now();
+    // simulated window, opening 5s after the start
+    let allowed = move |_| start.elapsed() >= Duration::from_secs(5);
+
+    let counter = Arc::new(AtomicUsize::new(0));
+    let cloned_counter = counter.clone();
+    let future = Retry::spawn_if_allowed(
+        FixedInterval::from_millis(100),
+        move || {
+            let previous = cloned_counter.fetch_add(1, Ordering::SeqCst);
+            future::ready(if previous < 1 {
+                Err::<(), RetryError<u64>>(RetryError::transient(42))
+            } else {
+                Ok(())
+            })
+        },
+        allowed,
+    )
+    .check_interval(Duration::from_millis(500));
+    assert_eq!(future.await, Ok(()));
+    assert_eq!(counter.load(Ordering::SeqCst), 2);
+    assert_eq!(start.elapsed(), Duration::from_millis(5100));
+
+    let start = tokio::time::Instant::now();
+    let future = Retry::spawn_if_allowed(
+        FixedInterval::from_millis(100),
+        || future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42))),
+        |_| false,
+    )
+    .abort_when_denied();
+    assert_eq!(future.await, Err(42));
+    assert_eq!(start.elapsed(), Duration::from_millis(100));
+}
@@ -0,0 +1,41 @@
+use std::{
+    future::{self, Ready},
+    time::Duration,
+};
+
+use tokio_retry2::{RetryError, retry_join, retry_join_within, strategy::FixedInterval};
+
+fn failing(times: usize) -> impl FnMut() -> Ready<Result<usize, RetryError<usize>>> {
+    let mut attempts = 0;
+    move || {
+        attempts += 1;
+        if attempts > times {
+            future::ready(Ok(attempts))
+        } else {
+            future::ready(Err(RetryError::transient(attempts)))
+        }
+    }
+}
+
+#[tokio::test]
+async fn joins_results_in_order() {
+    let results = retry_join(
+        || FixedInterval::from_millis(10).take(3),
+        [failing(0), failing(2), failing(usize::MAX)],
+    )
+    .await;
+
+    assert_eq!(results, vec![Ok(1), Ok(3), Err(4)]);
+}
+
+#[tokio::test]
+async fn joins_within_shared_budget() {
+    let results = retry_join_within(
+        || FixedInterval::from_millis(100),
+        [failing(1), failing(usize::MAX), failing(usize::MAX)],
+        Duration::from_millis(150),
+    )
+    .await;
+
+    assert_eq!(results, vec![Ok(2), Err(3), Err(3)]);
+}
@@ -0,0 +1,31 @@
+use std::{
+    future,
+    sync::{
+        Arc,
+        atomic::{AtomicUsize, Ordering},
+    },
+    time::Duration,
+};
+
+use tokio_retry2::{RetryError, RetryRunner, strategy::ExponentialBackoff};
+
+#[tokio::test(start_paused = true)]
+async fn each_run_gets_the_full_strategy() {
+    let runner = RetryRunner::new(ExponentialBackoff::from_millis(10).take(2));
+
+    for _ in 0..2 {
+        let counter = Arc::new(AtomicUsize::new(0));
+        let cloned_counter = counter.clone();
+        let start = tokio::time::Instant::now();
+        let res = runner
+            .run(move || {
+                cloned_counter.fetch_add(1, Ordering::SeqCst);
+                future::ready(Err::<(), _>(RetryError::transient(42)))
+            })
+            .await;
+
+        assert_eq!(res, Err(42));
+        assert_eq!(counter.load(Ordering::SeqCst), 3);
+        assert_eq!(start.elapsed(), Duration::from_millis(110));
+    }
+}
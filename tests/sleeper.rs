@@ -0,0 +1,78 @@
+#![cfg(feature = "sleeper")]
+use std::{
+    future,
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+use tokio_retry2::{Retry, RetryError, sleeper::TokioSleeper, strategy::FixedInterval};
+
+#[tokio::test]
+async fn custom_sleeper_receives_each_delay() {
+    let slept = Arc::new(Mutex::new(Vec::new()));
+    let cloned_slept = slept.clone();
+    let sleeper = move |duration| {
+        cloned_slept.lock().unwrap().push(duration);
+        future::ready(())
+    };
+    let mut attempts = 0;
+    let future = Retry::spawn_with_sleeper(
+        FixedInterval::from_millis(10).take(3),
+        move || {
+            attempts += 1;
+            future::ready(match attempts {
+                1 => Err(RetryError::transient(attempts)),
+                2 => Err(RetryError::retry_after(attempts, Duration::from_secs(5))),
+                _ => Ok::<u64, RetryError<u64>>(attempts),
+            })
+        },
+        sleeper,
+    );
+
+    assert_eq!(future.await, Ok(3));
+    assert_eq!(
+        *slept.lock().unwrap(),
+        vec![Duration::from_millis(10), Duration::from_secs(5)]
+    );
+}
+
+#[tokio::test(start_paused = true)]
+async fn tokio_sleeper_ends_with_the_last_error() {
+    let start = tokio::time::Instant::now();
+    let future = Retry::spawn_with_sleeper(
+        FixedInterval::from_millis(10).take(2),
+        || future::ready(Err::<(), RetryError<u64>>(RetryError::transient(42))),
+        TokioSleeper,
+    );
+
+    assert_eq!(future.await, Err(42));
+    assert_eq!(start.elapsed(), Duration::from_millis(20));
+}
+
+#[tokio::test(start_paused = true)]
+async fn custom_sleeper_honours_retry_at() {
+    let slept = Arc::new(Mutex::new(Vec::new()));
+    let cloned_slept = slept.clone();
+    let sleeper = move |duration| {
+        cloned_slept.lock().unwrap().push(duration);
+        future::ready(())
+    };
+    let retry_at = tokio::time::Instant::now() + Duration::from_secs(5);
+    let mut attempts = 0;
+    let future = Retry::spawn_with_sleeper(
+        FixedInterval::from_millis(10).take(3),
+        move || {
+            attempts += 1;
+            future::ready(match attempts {
+                1 => Err(RetryError::retry_at(attempts, retry_at)),
+                2 => Err(RetryError::retry_after(attempts, Duration::ZERO)),
+                _ => Ok::<u64, RetryError<u64>>(attempts),
+            })
+        },
+        sleeper,
+    );
+
+    assert_eq!(future.await, Ok(3));
+    // zero delays yield instead of sleeping
+    assert_eq!(*slept.lock().unwrap(), vec![Duration::from_secs(5)]);
+}
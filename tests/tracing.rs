@@ -0,0 +1,90 @@
+#![cfg(feature = "tracing")]
+use std::{
+    collections::HashMap,
+    fmt, future,
+    sync::{Arc, Mutex},
+};
+
+use tokio_retry2::{Retry, RetryError, strategy::FixedInterval};
+use tracing::{
+    Event, Metadata, Subscriber,
+    field::{Field, Visit},
+    span::{Attributes, Id, Record},
+    subscriber::Interest,
+};
+
+/// Records the `operation_id` of every span, and the one of the parent of every event.
+#[derive(Default, Clone)]
+struct Recorder {
+    spans: Arc<Mutex<HashMap<u64, String>>>,
+    events: Arc<Mutex<Vec<Option<String>>>>,
+}
+
+struct OperationId(Option<String>);
+
+impl Visit for OperationId {
+    fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+        if field.name() == "operation_id" {
+            self.0 = Some(format!("{value:?}"));
+        }
+    }
+}
+
+impl Subscriber for Recorder {
+    fn register_callsite(&self, _: &'static Metadata<'static>) -> Interest {
+        Interest::always()
+    }
+
+    fn enabled(&self, _: &Metadata<'_>) -> bool {
+        true
+    }
+
+    fn new_span(&self, span: &Attributes<'_>) -> Id {
+        let mut visitor = OperationId(None);
+        span.record(&mut visitor);
+        let mut spans = self.spans.lock().unwrap();
+        let id = spans.len() as u64 + 1;
+        spans.insert(id, visitor.0.unwrap_or_default());
+        drop(spans);
+        Id::from_u64(id)
+    }
+
+    fn record(&self, _: &Id, _: &Record<'_>) {}
+
+    fn record_follows_from(&self, _: &Id, _: &Id) {}
+
+    fn event(&self, event: &Event<'_>) {
+        let operation_id = event
+            .parent()
+            .and_then(|id| self.spans.lock().unwrap().get(&id.into_u64()).cloned());
+        self.events.lock().unwrap().push(operation_id);
+    }
+
+    fn enter(&self, _: &Id) {}
+
+    fn exit(&self, _: &Id) {}
+}
+
+#[tokio::test]
+async fn operation_id_is_recorded_on_each_attempt() {
+    let recorder = Recorder::default();
+    let _guard = tracing::subscriber::set_default(recorder.clone());
+
+    let future = Retry::spawn_with_operation_id(
+        FixedInterval::from_millis(1).take(2),
+        || future::ready(Err::<(), RetryError<()>>(RetryError::transient(()))),
+        "op-42",
+    );
+
+    assert_eq!(future.await, Err(()));
+    let attempts: Vec<_> = recorder
+        .events
+        .lock()
+        .unwrap()
+        .iter()
+        .flatten()
+        .cloned()
+        .collect();
+    assert_eq!(attempts, vec!["op-42"; 3]);
+    assert_eq!(recorder.spans.lock().unwrap().len(), 3);
+}